@@ -21,6 +21,9 @@ pub struct DropdownProps {
     /// The content of the trigger button.
     #[prop_or_default]
     pub button_html: Html,
+    /// Close the dropdown menu when any of its content is clicked.
+    #[prop_or_default]
+    pub close_on_click: bool,
 }
 
 /// Dropdown actions.
@@ -76,6 +79,11 @@ impl Component for Dropdown {
         } else {
             html! {}
         };
+        let contentcb = if self.props.close_on_click {
+            self.link.callback(|_| DropdownMsg::Close)
+        } else {
+            Callback::noop()
+        };
         html! {
             <div class=classes>
                 {overlay}
@@ -85,7 +93,7 @@ impl Component for Dropdown {
                     </Button>
                 </div>
                 <div class="dropdown-menu" role="menu">
-                    <div class="dropdown-content">
+                    <div class="dropdown-content" onclick=contentcb>
                         {self.props.children.clone()}
                     </div>
                 </div>
@@ -89,6 +89,12 @@ pub struct PaginationItemProps {
     /// The click handler for this component.
     #[prop_or_else(Callback::noop)]
     pub onclick: Callback<MouseEvent>,
+    /// Mark this component as the link to the current page.
+    #[prop_or_default]
+    pub current: bool,
+    /// Disable this component.
+    #[prop_or_default]
+    pub disabled: bool,
 }
 
 /// A pagination element representing a link to a page number, the previous page or the next page.
@@ -115,8 +121,21 @@ impl Component for PaginationItem {
     }
 
     fn view(&self) -> Html {
+        let mut classes = Classes::from(self.props.item_type.to_string());
+        let aria_current = if self.props.current {
+            classes.push("is-current");
+            Some("page")
+        } else {
+            None
+        };
         html! {
-            <a class=self.props.item_type.to_string() aria-label=self.props.label.clone() onclick=self.props.onclick.clone()>
+            <a
+                class=classes
+                aria-label=self.props.label.clone()
+                aria-current=aria_current
+                onclick=self.props.onclick.clone()
+                disabled=self.props.disabled
+            >
                 {self.props.children.clone()}
             </a>
         }
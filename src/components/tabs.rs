@@ -1,6 +1,15 @@
+#![allow(clippy::unnecessary_operation)]
+
+use std::collections::HashSet;
+
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
 use yew::prelude::*;
+use yew::services::resize::{ResizeService, ResizeTask};
+use yew::virtual_dom::VNode;
 use yewtil::NeqAssign;
 
+use crate::components::dropdown::Dropdown;
 use crate::{Alignment, Size};
 
 #[derive(Clone, Debug, Properties, PartialEq)]
@@ -35,6 +44,14 @@ pub struct TabsProps {
 ///
 /// For integration with Yew Router, it is recommended that the `RouterButton` or `RouterAnchor`
 /// components be used as the individual tab elements for this component.
+///
+/// The list is marked as a WAI-ARIA `tablist`. Each `li` child whose first child is an element,
+/// such as an `a`, is given the `presentation` role, while that element is given the `tab` role,
+/// is `aria-selected` while the `li` has the `is-active` class, and is only in the page's tab
+/// sequence while active. The `Left`, `Right`, `Home` & `End` keys move focus between these tabs,
+/// and `Enter` & `Space` click the focused tab. Tabs rendered by components, such as
+/// `RouterAnchor`s, are left as-is. For tab panels & automatic activation, see the `TabView`
+/// component.
 pub struct Tabs {
     props: TabsProps,
 }
@@ -56,32 +73,793 @@ impl Component for Tabs {
     }
 
     fn view(&self) -> Html {
-        let mut classes = Classes::from("tabs");
-        classes.push(&self.props.classes);
-        if let Some(alignment) = &self.props.alignment {
-            classes.push(&alignment.to_string());
+        let classes = tabs_classes(
+            &self.props.classes,
+            &self.props.alignment,
+            &self.props.size,
+            TabsStyle {
+                boxed: self.props.boxed,
+                toggle: self.props.toggle,
+                rounded: self.props.rounded,
+                fullwidth: self.props.fullwidth,
+            },
+        );
+        let items = self.props.children.iter().collect::<Vec<_>>();
+        let active = items.iter().map(is_active_item).collect::<Vec<_>>();
+        let focusable = active.iter().position(|active| *active).unwrap_or(0);
+        let tabs = items
+            .into_iter()
+            .enumerate()
+            .map(|(idx, item)| with_tab_semantics(item, active[idx], idx == focusable))
+            .collect::<Html>();
+        html! {
+            <div class=classes>
+                <ul role="tablist" onkeydown=Callback::from(tabs_keydown)>
+                    {tabs}
+                </ul>
+            </div>
         }
-        if let Some(size) = &self.props.size {
-            classes.push(&size.to_string());
+    }
+}
+
+/// Check if the given child of a `Tabs` component is an `li.is-active` element.
+fn is_active_item(item: &Html) -> bool {
+    match item {
+        VNode::VTag(li) if li.tag() == "li" => li
+            .attributes
+            .iter()
+            .any(|(key, value)| key == "class" && value.split_whitespace().any(|class| class == "is-active")),
+        _ => false,
+    }
+}
+
+/// Give the tab element of the given child of a `Tabs` component, if any, the `tab` role.
+fn with_tab_semantics(mut item: Html, selected: bool, focusable: bool) -> Html {
+    if let VNode::VTag(li) = &mut item {
+        if li.tag() != "li" {
+            return item;
         }
-        if self.props.boxed {
-            classes.push("is-boxed");
+        if let Some(VNode::VTag(tab)) = li.children.children.first_mut() {
+            tab.add_attribute("role", "tab");
+            tab.add_attribute("aria-selected", selected.to_string());
+            tab.add_attribute("tabindex", if focusable { "0" } else { "-1" });
+            li.add_attribute("role", "presentation");
         }
-        if self.props.toggle {
-            classes.push("is-toggle");
+    }
+    item
+}
+
+/// Handle the keyboard navigation of the tab elements of a `Tabs` component.
+fn tabs_keydown(event: KeyboardEvent) {
+    let list = match event.current_target().and_then(|target| target.dyn_into::<Element>().ok()) {
+        Some(list) => list,
+        None => return,
+    };
+    let items = list.children();
+    let tabs = (0..items.length())
+        .filter_map(|idx| items.item(idx)?.first_element_child())
+        .filter(|tab| tab.get_attribute("role").as_deref() == Some("tab"))
+        .filter_map(|tab| tab.dyn_into::<HtmlElement>().ok())
+        .collect::<Vec<_>>();
+    let target = event.target().and_then(|target| target.dyn_into::<HtmlElement>().ok());
+    let current = match tabs.iter().position(|tab| Some(tab) == target.as_ref()) {
+        Some(current) => current,
+        None => return,
+    };
+    let next = match event.key().as_str() {
+        "ArrowLeft" => (current + tabs.len() - 1) % tabs.len(),
+        "ArrowRight" => (current + 1) % tabs.len(),
+        "Home" => 0,
+        "End" => tabs.len() - 1,
+        "Enter" | " " => {
+            event.prevent_default();
+            tabs[current].click();
+            return;
+        }
+        _ => return,
+    };
+    event.prevent_default();
+    tabs[current].set_tab_index(-1);
+    tabs[next].set_tab_index(0);
+    let _ = tabs[next].focus();
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+/// A single tab of a `TabView`, along with the content of its panel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TabItem {
+    /// The ID of this tab, unique within its `TabView`.
+    ///
+    /// This value is used to generate the `id` attributes of the tab & its panel, and is the value
+    /// emitted by the `TabView`'s `onchange` callback.
+    pub id: String,
+    /// The contents of the tab element.
+    pub label: Html,
+    /// The contents of this tab's panel.
+    pub content: Html,
+    /// Disable this tab; it can be neither focused via the keyboard nor activated.
+    pub disabled: bool,
+    /// The policy used for mounting this tab's panel content.
+    pub render: TabPanelRender,
+}
+
+impl TabItem {
+    /// Create a new enabled tab with the given ID, label & panel content.
+    pub fn new(id: impl Into<String>, label: Html, content: Html) -> Self {
+        Self {
+            id: id.into(),
+            label,
+            content,
+            disabled: false,
+            render: TabPanelRender::Unmount,
         }
-        if self.props.rounded {
-            classes.push("is-rounded");
+    }
+}
+
+/// The policy used for mounting the content of a `TabView` panel.
+///
+/// Panels which remain mounted while their tab is inactive are hidden via the `hidden` attribute,
+/// and any components within them retain their state.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TabPanelRender {
+    /// Mount the content only while its tab is active, unmounting it when another tab is activated.
+    Unmount,
+    /// Mount the content the first time its tab is activated, and keep it mounted afterwards.
+    Lazy,
+    /// Mount the content right away, and keep it mounted regardless of which tab is active.
+    KeepAlive,
+}
+
+/// The way in which keyboard navigation activates the tabs of a `TabView`.
+///
+/// [https://www.w3.org/TR/wai-aria-practices/#kbd_selection_follows_focus](https://www.w3.org/TR/wai-aria-practices/#kbd_selection_follows_focus)
+#[derive(Clone, Debug, PartialEq)]
+pub enum TabActivation {
+    /// Tabs are activated as soon as they receive focus.
+    Automatic,
+    /// Tabs receive focus via the arrow keys, and are only activated via `Enter` or `Space`.
+    Manual,
+}
+
+/// The way in which a `TabView` handles tabs overflowing its width.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TabsOverflow {
+    /// Show buttons for scrolling the tabs left & right while the tabs overflow.
+    Scroll,
+    /// Show the scroll buttons, along with a "more" dropdown menu listing the tabs which are
+    /// scrolled out of view.
+    Collapse,
+}
+
+/// The message type used by the `TabView` component.
+pub enum TabViewMsg {
+    /// Activate the tab at the given index.
+    Select(usize),
+    /// A key was pressed while a tab had focus.
+    KeyDown(KeyboardEvent),
+    /// Scroll the tabs to the left, or to the right when `true`.
+    Scroll(bool),
+    /// Measure which of the tabs overflow the width of the component.
+    Measure,
+}
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct TabViewProps {
+    /// The ID of this component, used as a prefix for the `id` attributes of its tabs & panels.
+    pub id: String,
+    /// The tabs of this component.
+    pub items: Vec<TabItem>,
+    /// The ID of the active tab; defaults to the first enabled tab.
+    #[prop_or_default]
+    pub active: Option<String>,
+    /// The callback to be used for propagating the ID of the newly activated tab.
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<String>,
+    /// The way in which keyboard navigation activates tabs.
+    #[prop_or_else(|| TabActivation::Automatic)]
+    pub activation: TabActivation,
+    /// Handle tabs overflowing the width of this component; by default, the tabs are only
+    /// scrollable natively.
+    #[prop_or_default]
+    pub overflow: Option<TabsOverflow>,
+    /// The content of the button which scrolls the tabs to the left.
+    #[prop_or_else(|| html! {"\u{2039}"})]
+    pub scroll_left_html: Html,
+    /// The content of the button which scrolls the tabs to the right.
+    #[prop_or_else(|| html! {"\u{203a}"})]
+    pub scroll_right_html: Html,
+    /// The content of the trigger button of the "more" dropdown menu.
+    #[prop_or_else(|| html! {"More"})]
+    pub more_html: Html,
+
+    #[prop_or_default]
+    pub classes: Option<Classes>,
+    /// Extra classes for each of the tab panels.
+    #[prop_or_default]
+    pub panel_classes: Option<Classes>,
+    /// The alignment of this component.
+    #[prop_or_default]
+    pub alignment: Option<Alignment>,
+    /// The size of this component.
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Add a more classic style with borders to this component.
+    #[prop_or_default]
+    pub boxed: bool,
+    /// Add the "radio button" style to the elements of this component.
+    #[prop_or_default]
+    pub toggle: bool,
+    /// Make the tab elements of this component rounded.
+    #[prop_or_default]
+    pub rounded: bool,
+    /// Make this component fullwidth.
+    #[prop_or_default]
+    pub fullwidth: bool,
+}
+
+/// Accessible tabs along with their panels, following the WAI-ARIA tabs pattern.
+///
+/// [https://bulma.io/documentation/components/tabs/](https://bulma.io/documentation/components/tabs/)
+///
+/// Tabs are rendered with the `tab` role inside of a `tablist`, and the panel of each tab is
+/// rendered below the tabs with the `tabpanel` role. Only the focused tab is part of the page's
+/// tab sequence; the `Left` & `Right` arrow keys move focus between tabs, while `Home` & `End`
+/// move focus to the first & last tabs. See `TabActivation` for how focus relates to activation.
+///
+/// When the `overflow` prop is set, this component tracks which of its tabs overflow its width &
+/// keeps the active tab scrolled into view. See `TabsOverflow` for the available modes.
+pub struct TabView {
+    link: ComponentLink<Self>,
+    props: TabViewProps,
+    /// The index of the active tab.
+    active: usize,
+    /// The index of the tab which takes part in the page's tab sequence.
+    focused: usize,
+    /// A flag indicating that the focused tab should receive focus after the next render.
+    focus_pending: bool,
+    tab_refs: Vec<NodeRef>,
+    /// The IDs of the tabs which have been activated at least once.
+    visited: HashSet<String>,
+    /// The scroll container of the tabs, being the `div.tabs` element.
+    tabs_ref: NodeRef,
+    overflow_state: OverflowState,
+    /// A flag indicating that the active tab should be scrolled into view after the next render.
+    scroll_pending: bool,
+    #[allow(dead_code)]
+    resize_task: Option<ResizeTask>,
+}
+
+/// The measured overflow of a `TabView`'s tabs.
+#[derive(Default, PartialEq)]
+struct OverflowState {
+    /// The tabs can be scrolled further to the left.
+    can_scroll_left: bool,
+    /// The tabs can be scrolled further to the right.
+    can_scroll_right: bool,
+    /// The indices of the tabs which are not fully within view.
+    hidden: Vec<usize>,
+}
+
+impl Component for TabView {
+    type Message = TabViewMsg;
+    type Properties = TabViewProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let active = Self::initial_index(&props);
+        let tab_refs = props.items.iter().map(|_| NodeRef::default()).collect();
+        let visited = props.items.get(active).map(|item| item.id.clone()).into_iter().collect();
+        let resize_task = Self::register_resize(&props, &link);
+        Self {
+            link,
+            props,
+            active,
+            focused: active,
+            focus_pending: false,
+            tab_refs,
+            visited,
+            tabs_ref: NodeRef::default(),
+            overflow_state: OverflowState::default(),
+            scroll_pending: true,
+            resize_task,
         }
-        if self.props.fullwidth {
-            classes.push("is-fullwidth");
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            TabViewMsg::Select(idx) => {
+                self.focused = idx;
+                self.activate(idx)
+            }
+            TabViewMsg::KeyDown(event) => {
+                let target = match event.key().as_str() {
+                    "ArrowLeft" => self.step(self.focused, false),
+                    "ArrowRight" => self.step(self.focused, true),
+                    "Home" => self.step(self.props.items.len().saturating_sub(1), true),
+                    "End" => self.step(0, false),
+                    "Enter" | " " => {
+                        event.prevent_default();
+                        return self.activate(self.focused);
+                    }
+                    _ => return false,
+                };
+                event.prevent_default();
+                let idx = match target {
+                    Some(idx) => idx,
+                    None => return false,
+                };
+                self.focused = idx;
+                self.focus_pending = true;
+                if self.props.activation == TabActivation::Automatic {
+                    self.activate(idx);
+                }
+                true
+            }
+            TabViewMsg::Scroll(forward) => {
+                if let Some(container) = self.tabs_ref.cast::<Element>() {
+                    let delta = container.client_width() * 2 / 3;
+                    container.set_scroll_left(container.scroll_left() + if forward { delta } else { -delta });
+                }
+                false
+            }
+            TabViewMsg::Measure => {
+                let state = self.measure();
+                self.overflow_state.neq_assign(state)
+            }
         }
-        html! {
-            <div class=classes>
-                <ul>
-                    {self.props.children.clone()}
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props.items.len() != self.tab_refs.len() {
+            self.tab_refs = props.items.iter().map(|_| NodeRef::default()).collect();
+        }
+        let active_changed = props.active != self.props.active || props.items.len() != self.props.items.len();
+        if props.overflow.is_some() != self.props.overflow.is_some() {
+            self.resize_task = Self::register_resize(&props, &self.link);
+            self.overflow_state = OverflowState::default();
+        }
+        let should_render = self.props.neq_assign(props);
+        if active_changed {
+            self.active = Self::initial_index(&self.props);
+            self.focused = self.active;
+        }
+        let items = &self.props.items;
+        self.visited.retain(|id| items.iter().any(|item| &item.id == id));
+        if let Some(item) = items.get(self.active) {
+            self.visited.insert(item.id.clone());
+        }
+        should_render
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        if self.focus_pending {
+            self.focus_pending = false;
+            if let Some(elem) = self.tab_refs.get(self.focused).and_then(|node| node.cast::<HtmlElement>()) {
+                let _ = elem.focus();
+            }
+        }
+        if self.props.overflow.is_some() {
+            if self.scroll_pending {
+                self.scroll_pending = false;
+                self.scroll_active_into_view();
+            }
+            self.link.send_message(TabViewMsg::Measure);
+        }
+    }
+
+    fn view(&self) -> Html {
+        let classes = tabs_classes(
+            &self.props.classes,
+            &self.props.alignment,
+            &self.props.size,
+            TabsStyle {
+                boxed: self.props.boxed,
+                toggle: self.props.toggle,
+                rounded: self.props.rounded,
+                fullwidth: self.props.fullwidth,
+            },
+        );
+        let tabs = self
+            .props
+            .items
+            .iter()
+            .enumerate()
+            .map(|(idx, item)| self.view_tab(idx, item))
+            .collect::<Html>();
+        let panels = self
+            .props
+            .items
+            .iter()
+            .enumerate()
+            .map(|(idx, item)| self.view_panel(idx, item))
+            .collect::<Html>();
+        let tabs = html! {
+            <div class=classes ref=self.tabs_ref.clone() onscroll=self.link.callback(|_| TabViewMsg::Measure)>
+                <ul role="tablist" onkeydown=self.link.callback(TabViewMsg::KeyDown)>
+                    {tabs}
                 </ul>
             </div>
+        };
+        html! {
+            <>
+            {self.view_overflow(tabs)}
+            {panels}
+            </>
+        }
+    }
+}
+
+impl TabView {
+    /// The index of the tab matching the `active` prop, else the first enabled tab.
+    fn initial_index(props: &TabViewProps) -> usize {
+        props
+            .active
+            .as_ref()
+            .and_then(|id| props.items.iter().position(|item| &item.id == id))
+            .or_else(|| props.items.iter().position(|item| !item.disabled))
+            .unwrap_or(0)
+    }
+
+    /// Find the next enabled tab after (or before) the given index, wrapping around the ends.
+    fn step(&self, from: usize, forward: bool) -> Option<usize> {
+        let len = self.props.items.len();
+        (1..=len)
+            .map(|offset| if forward { (from + offset) % len } else { (from + len - offset) % len })
+            .find(|idx| !self.props.items[*idx].disabled)
+    }
+
+    /// Activate the tab at the given index, emitting its ID if it was not already active.
+    fn activate(&mut self, idx: usize) -> ShouldRender {
+        match self.props.items.get(idx) {
+            Some(item) if !item.disabled && idx != self.active => {
+                self.active = idx;
+                self.visited.insert(item.id.clone());
+                self.scroll_pending = true;
+                self.props.onchange.emit(item.id.clone());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Register a window resize listener for re-measuring overflow, if overflow is handled.
+    fn register_resize(props: &TabViewProps, link: &ComponentLink<Self>) -> Option<ResizeTask> {
+        props
+            .overflow
+            .as_ref()
+            .map(|_| ResizeService::register(link.callback(|_| TabViewMsg::Measure)))
+    }
+
+    /// Measure the scroll position of the tabs, along with which tabs are out of view.
+    fn measure(&self) -> OverflowState {
+        let container = match self.tabs_ref.cast::<Element>() {
+            Some(container) => container,
+            None => return OverflowState::default(),
+        };
+        let scroll_left = container.scroll_left();
+        let max_scroll_left = container.scroll_width() - container.client_width();
+        let hidden = if self.props.overflow == Some(TabsOverflow::Collapse) {
+            let bounds = container.get_bounding_client_rect();
+            self.tab_refs
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, node)| {
+                    let rect = node.cast::<Element>()?.get_bounding_client_rect();
+                    // Allow for sub-pixel rounding of the tab positions.
+                    if rect.left() < bounds.left() - 1.0 || rect.right() > bounds.right() + 1.0 {
+                        Some(idx)
+                    } else {
+                        None
+                    }
+                })
+                .collect()
+        } else {
+            vec![]
+        };
+        OverflowState {
+            can_scroll_left: scroll_left > 0,
+            can_scroll_right: max_scroll_left - scroll_left > 1,
+            hidden,
+        }
+    }
+
+    /// Scroll the tabs so that the active tab is fully within view.
+    fn scroll_active_into_view(&self) {
+        let container = self.tabs_ref.cast::<Element>();
+        let tab = self.tab_refs.get(self.active).and_then(|node| node.cast::<Element>());
+        if let (Some(container), Some(tab)) = (container, tab) {
+            let bounds = container.get_bounding_client_rect();
+            let rect = tab.get_bounding_client_rect();
+            if rect.left() < bounds.left() {
+                container.set_scroll_left(container.scroll_left() - (bounds.left() - rect.left()).ceil() as i32);
+            } else if rect.right() > bounds.right() {
+                container.set_scroll_left(container.scroll_left() + (rect.right() - bounds.right()).ceil() as i32);
+            }
+        }
+    }
+
+    /// Wrap the tabs with the scroll buttons & "more" dropdown menu, according to the overflow mode.
+    fn view_overflow(&self, tabs: Html) -> Html {
+        let mode = match &self.props.overflow {
+            Some(mode) => mode,
+            None => return tabs,
+        };
+        let state = &self.overflow_state;
+        let buttons = if state.can_scroll_left || state.can_scroll_right {
+            (
+                html! {
+                    <button
+                        class="button is-white"
+                        aria-label="Scroll tabs left"
+                        tabindex="-1"
+                        disabled=!state.can_scroll_left
+                        onclick=self.link.callback(|_| TabViewMsg::Scroll(false))
+                    >
+                        {self.props.scroll_left_html.clone()}
+                    </button>
+                },
+                html! {
+                    <button
+                        class="button is-white"
+                        aria-label="Scroll tabs right"
+                        tabindex="-1"
+                        disabled=!state.can_scroll_right
+                        onclick=self.link.callback(|_| TabViewMsg::Scroll(true))
+                    >
+                        {self.props.scroll_right_html.clone()}
+                    </button>
+                },
+            )
+        } else {
+            (html! {}, html! {})
+        };
+        let more = if mode == &TabsOverflow::Collapse && !state.hidden.is_empty() {
+            let items = state
+                .hidden
+                .iter()
+                .filter_map(|idx| self.props.items.get(*idx).map(|item| (*idx, item)))
+                .filter(|(_, item)| !item.disabled)
+                .map(|(idx, item)| {
+                    let classes = if idx == self.active {
+                        "dropdown-item is-active"
+                    } else {
+                        "dropdown-item"
+                    };
+                    html! {
+                        <a class=classes onclick=self.link.callback(move |_| TabViewMsg::Select(idx))>
+                            {item.label.clone()}
+                        </a>
+                    }
+                })
+                .collect::<Html>();
+            html! {
+                <Dropdown classes=Classes::from("is-right") button_html=self.props.more_html.clone() close_on_click=true>
+                    {items}
+                </Dropdown>
+            }
+        } else {
+            html! {}
+        };
+        html! {
+            <div class="is-flex is-align-items-center">
+                {buttons.0}
+                <div style="flex-grow:1;min-width:0;">
+                    {tabs}
+                </div>
+                {buttons.1}
+                {more}
+            </div>
+        }
+    }
+
+    fn tab_id(&self, item: &TabItem) -> String {
+        format!("{}-tab-{}", self.props.id, item.id)
+    }
+
+    fn panel_id(&self, item: &TabItem) -> String {
+        format!("{}-panel-{}", self.props.id, item.id)
+    }
+
+    fn view_tab(&self, idx: usize, item: &TabItem) -> Html {
+        let is_active = idx == self.active;
+        let li_classes = if is_active { Classes::from("is-active") } else { Classes::new() };
+        let tabindex = if idx == self.focused { "0" } else { "-1" };
+        let onclick = if item.disabled {
+            Callback::noop()
+        } else {
+            self.link.callback(move |_| TabViewMsg::Select(idx))
+        };
+        html! {
+            <li class=li_classes role="presentation">
+                <a
+                    ref=self.tab_refs.get(idx).cloned().unwrap_or_default()
+                    id=self.tab_id(item)
+                    role="tab"
+                    aria-selected=is_active.to_string()
+                    aria-controls=self.panel_id(item)
+                    aria-disabled=item.disabled.to_string()
+                    tabindex=tabindex
+                    onclick=onclick
+                >
+                    {item.label.clone()}
+                </a>
+            </li>
+        }
+    }
+
+    fn view_panel(&self, idx: usize, item: &TabItem) -> Html {
+        let is_active = idx == self.active;
+        let mut classes = Classes::new();
+        classes.push(&self.props.panel_classes);
+        let is_mounted = match item.render {
+            TabPanelRender::Unmount => is_active,
+            TabPanelRender::Lazy => is_active || self.visited.contains(&item.id),
+            TabPanelRender::KeepAlive => true,
+        };
+        let content = if is_mounted {
+            item.content.clone()
+        } else {
+            html! {}
+        };
+        html! {
+            <div
+                key=item.id.clone()
+                class=classes
+                id=self.panel_id(item)
+                role="tabpanel"
+                aria-labelledby=self.tab_id(item)
+                tabindex="0"
+                hidden=!is_active
+            >
+                {content}
+            </div>
+        }
+    }
+}
+
+/// The boolean style modifiers shared by the tab components.
+struct TabsStyle {
+    boxed: bool,
+    toggle: bool,
+    rounded: bool,
+    fullwidth: bool,
+}
+
+/// Build the classes of the `div.tabs` container.
+fn tabs_classes(extra: &Option<Classes>, alignment: &Option<Alignment>, size: &Option<Size>, style: TabsStyle) -> Classes {
+    let mut classes = Classes::from("tabs");
+    classes.push(extra);
+    if let Some(alignment) = alignment {
+        classes.push(alignment.to_string());
+    }
+    if let Some(size) = size {
+        classes.push(size.to_string());
+    }
+    if style.boxed {
+        classes.push("is-boxed");
+    }
+    if style.toggle {
+        classes.push("is-toggle");
+    }
+    if style.rounded {
+        classes.push("is-rounded");
+    }
+    if style.fullwidth {
+        classes.push("is-fullwidth");
+    }
+    classes
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "router")]
+mod router {
+    use super::*;
+    use yew_router::agent::{RouteAgentBridge, RouteRequest};
+    use yew_router::components::RouterAnchor;
+    use yew_router::route::Route;
+    use yew_router::{RouterState, Switch};
+
+    #[derive(Clone, Properties, PartialEq)]
+    pub struct TabsRouterProps<SW: Switch + Clone + PartialEq + 'static> {
+        /// The tabs of this component, as pairs of the tab's label & the route it links to.
+        pub tabs: Vec<(Html, SW)>,
+        #[prop_or_default]
+        pub classes: Option<Classes>,
+        /// The alignment of this component.
+        #[prop_or_default]
+        pub alignment: Option<Alignment>,
+        /// The size of this component.
+        #[prop_or_default]
+        pub size: Option<Size>,
+        /// Add a more classic style with borders to this component.
+        #[prop_or_default]
+        pub boxed: bool,
+        /// Add the "radio button" style to the elements of this component.
+        #[prop_or_default]
+        pub toggle: bool,
+        /// Make the tab elements of this component rounded.
+        #[prop_or_default]
+        pub rounded: bool,
+        /// Make this component fullwidth.
+        #[prop_or_default]
+        pub fullwidth: bool,
+    }
+
+    /// Navigation tabs linking to Yew Router routes, with the tab of the current route marked active.
+    ///
+    /// [https://bulma.io/documentation/components/tabs/](https://bulma.io/documentation/components/tabs/)
+    ///
+    /// Each tab is rendered as a `RouterAnchor` within an `li` element. This component subscribes to
+    /// route changes, and the `is-active` class is applied to the tab whose route is equal to the
+    /// current route.
+    pub struct TabsRouter<SW: Switch + Clone + PartialEq + 'static, STATE: RouterState = ()> {
+        props: TabsRouterProps<SW>,
+        #[allow(dead_code)]
+        router: RouteAgentBridge<STATE>,
+        current: Option<SW>,
+    }
+
+    impl<SW: Switch + Clone + PartialEq + 'static, STATE: RouterState> Component for TabsRouter<SW, STATE> {
+        type Message = Route<STATE>;
+        type Properties = TabsRouterProps<SW>;
+
+        fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+            let mut router = RouteAgentBridge::new(link.callback(|route| route));
+            router.send(RouteRequest::GetCurrentRoute);
+            Self { props, router, current: None }
+        }
+
+        fn update(&mut self, route: Self::Message) -> ShouldRender {
+            self.current.neq_assign(SW::switch(route))
+        }
+
+        fn change(&mut self, props: Self::Properties) -> ShouldRender {
+            self.props.neq_assign(props)
+        }
+
+        #[allow(deprecated)]
+        fn view(&self) -> Html {
+            let classes = tabs_classes(
+                &self.props.classes,
+                &self.props.alignment,
+                &self.props.size,
+                TabsStyle {
+                    boxed: self.props.boxed,
+                    toggle: self.props.toggle,
+                    rounded: self.props.rounded,
+                    fullwidth: self.props.fullwidth,
+                },
+            );
+            let tabs = self
+                .props
+                .tabs
+                .iter()
+                .map(|(label, route)| {
+                    let li_classes = if self.current.as_ref() == Some(route) {
+                        Classes::from("is-active")
+                    } else {
+                        Classes::new()
+                    };
+                    html! {
+                        <li class=li_classes>
+                            <RouterAnchor<SW, STATE> route=route.clone() children=Children::new(vec![label.clone()])/>
+                        </li>
+                    }
+                })
+                .collect::<Html>();
+            html! {
+                <div class=classes>
+                    <ul>
+                        {tabs}
+                    </ul>
+                </div>
+            }
         }
     }
 }
+
+#[cfg(feature = "router")]
+pub use router::{TabsRouter, TabsRouterProps};
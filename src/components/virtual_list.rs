@@ -0,0 +1,301 @@
+#![allow(clippy::unnecessary_operation)]
+
+use std::fmt;
+use std::rc::Rc;
+
+use web_sys::Element;
+use yew::prelude::*;
+use yew::services::resize::{ResizeService, ResizeTask};
+use yewtil::NeqAssign;
+
+/// The height of the rows of a virtualized list or table.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RowHeight {
+    /// Every row has the given height, in pixels.
+    Fixed(f64),
+    /// Rows are measured once they have been rendered; the given height, in pixels, is used for
+    /// the rows which have not been rendered yet.
+    Estimated(f64),
+}
+
+/// The range of rows to render for the current scroll position, along with the space taken up by
+/// the rows before & after the range.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Window {
+    /// The index of the first rendered row.
+    pub start: usize,
+    /// The index after the last rendered row.
+    pub end: usize,
+    /// The height of the rows before the rendered rows, in pixels.
+    pub before: f64,
+    /// The height of the rows after the rendered rows, in pixels.
+    pub after: f64,
+}
+
+/// The scroll state & row heights of a virtualized scroll area.
+pub(crate) struct Viewport {
+    row_height: RowHeight,
+    /// The measured heights of the rows, by row index; only used for estimated row heights.
+    heights: Vec<Option<f64>>,
+    /// The scroll offset of the scroll area, in pixels.
+    pub scroll_top: f64,
+    /// The visible height of the scroll area, in pixels.
+    pub height: f64,
+}
+
+impl Viewport {
+    pub fn new(row_height: RowHeight, len: usize) -> Self {
+        Self {
+            row_height,
+            heights: vec![None; len],
+            scroll_top: 0.0,
+            height: 0.0,
+        }
+    }
+
+    /// Update the row height setting & the number of rows, retaining the measured row heights.
+    pub fn update(&mut self, row_height: RowHeight, len: usize) {
+        if row_height != self.row_height {
+            self.row_height = row_height;
+            self.heights = vec![None; len];
+        }
+        self.heights.resize(len, None);
+    }
+
+    /// The height of the row at the given index, measured or otherwise.
+    fn row_height(&self, idx: usize) -> f64 {
+        match self.row_height {
+            RowHeight::Fixed(height) => height,
+            RowHeight::Estimated(estimate) => self.heights.get(idx).copied().flatten().unwrap_or(estimate),
+        }
+    }
+
+    /// Compute the rows to render, including `overscan` extra rows before & after the visible rows.
+    pub fn window(&self, overscan: usize) -> Window {
+        let len = self.heights.len();
+        let bottom = self.scroll_top + self.height;
+        let (mut start, mut end) = (len, len);
+        let mut offset = 0.0;
+        let mut offsets = Vec::with_capacity(len + 1);
+        for idx in 0..len {
+            offsets.push(offset);
+            let height = self.row_height(idx);
+            if start == len && offset + height > self.scroll_top {
+                start = idx;
+            }
+            if end == len && offset >= bottom {
+                end = idx;
+            }
+            offset += height;
+        }
+        offsets.push(offset);
+        // Always render at least one row, so that a scroll area without height can be measured.
+        let start = start.saturating_sub(overscan).min(len.saturating_sub(1));
+        let end = (end + overscan).min(len).max((start + 1).min(len));
+        Window {
+            start,
+            end,
+            before: offsets[start],
+            after: offset - offsets[end],
+        }
+    }
+
+    /// Record the measured heights of the rendered rows, starting at the given index; returns
+    /// `true` if any of the heights changed.
+    pub fn measure(&mut self, start: usize, heights: impl Iterator<Item = f64>) -> bool {
+        if let RowHeight::Fixed(_) = self.row_height {
+            return false;
+        }
+        let mut changed = false;
+        for (slot, height) in self.heights.iter_mut().skip(start).zip(heights) {
+            // Ignore sub-pixel differences, which would otherwise cause endless re-renders.
+            if slot.map(|old| (old - height).abs() > 0.5).unwrap_or(true) {
+                *slot = Some(height);
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Read the scroll position & height of the given scroll area; returns `true` if either changed.
+    pub fn scroll(&mut self, container: &Element) -> bool {
+        let scroll_top = f64::from(container.scroll_top());
+        let height = f64::from(container.client_height());
+        let changed = scroll_top != self.scroll_top || height != self.height;
+        self.scroll_top = scroll_top;
+        self.height = height;
+        changed
+    }
+}
+
+/// Measure the heights of the child elements of the given element.
+pub(crate) fn child_heights(parent: &Element) -> Vec<f64> {
+    let children = parent.children();
+    (0..children.length())
+        .filter_map(|idx| children.item(idx))
+        .map(|child| child.get_bounding_client_rect().height())
+        .collect()
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+/// A function rendering an item of a `VirtualList`.
+pub struct ItemRenderer<T>(Rc<dyn Fn(&T) -> Html>);
+
+impl<T> ItemRenderer<T> {
+    /// Create a new item renderer from the given function.
+    pub fn new(render: impl Fn(&T) -> Html + 'static) -> Self {
+        Self(Rc::new(render))
+    }
+
+    /// Render the given item.
+    pub(crate) fn render(&self, item: &T) -> Html {
+        (self.0)(item)
+    }
+}
+
+impl<T> Clone for ItemRenderer<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// Item renderers are equal when they share the same function.
+impl<T> PartialEq for ItemRenderer<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T> fmt::Debug for ItemRenderer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ItemRenderer").finish()
+    }
+}
+
+/// The message type used by the virtualized components.
+pub enum VirtualListMsg {
+    /// The scroll area was scrolled or resized.
+    Scroll,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct VirtualListProps<T: Clone + PartialEq + 'static> {
+    /// The items of this list.
+    pub items: Vec<T>,
+    /// The function rendering each item of this list.
+    pub render: ItemRenderer<T>,
+    /// The height of the items of this list.
+    pub row_height: RowHeight,
+    #[prop_or_default]
+    pub classes: Option<Classes>,
+    /// The maximum height of the scroll area of this list, as a CSS length.
+    #[prop_or_else(|| "400px".into())]
+    pub height: String,
+    /// The number of extra items to render before & after the visible items.
+    #[prop_or_else(|| 5)]
+    pub overscan: usize,
+}
+
+/// A scrollable list which only renders the items within view.
+///
+/// Items outside of the scroll area are replaced with empty space of the same height, so that
+/// lists of many thousands of items can be rendered without degrading performance. Each item is
+/// rendered within its own `div`, and the scroll position is preserved as the items change.
+///
+/// See `RowHeight` for how the heights of the items are determined.
+pub struct VirtualList<T: Clone + PartialEq + 'static> {
+    link: ComponentLink<Self>,
+    props: VirtualListProps<T>,
+    viewport: Viewport,
+    window: Window,
+    container_ref: NodeRef,
+    items_ref: NodeRef,
+    /// A flag indicating that the scroll position should be restored after the next render.
+    restore_scroll: bool,
+    #[allow(dead_code)]
+    resize_task: ResizeTask,
+}
+
+impl<T: Clone + PartialEq + 'static> Component for VirtualList<T> {
+    type Message = VirtualListMsg;
+    type Properties = VirtualListProps<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let viewport = Viewport::new(props.row_height.clone(), props.items.len());
+        let window = viewport.window(props.overscan);
+        let resize_task = ResizeService::register(link.callback(|_| VirtualListMsg::Scroll));
+        Self {
+            link,
+            props,
+            viewport,
+            window,
+            container_ref: NodeRef::default(),
+            items_ref: NodeRef::default(),
+            restore_scroll: false,
+            resize_task,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            VirtualListMsg::Scroll => {
+                if let Some(container) = self.container_ref.cast::<Element>() {
+                    self.viewport.scroll(&container);
+                }
+                self.window.neq_assign(self.viewport.window(self.props.overscan))
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.neq_assign(props) {
+            self.viewport.update(self.props.row_height.clone(), self.props.items.len());
+            self.window = self.viewport.window(self.props.overscan);
+            self.restore_scroll = true;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if self.restore_scroll {
+            self.restore_scroll = false;
+            if let Some(container) = self.container_ref.cast::<Element>() {
+                container.set_scroll_top(self.viewport.scroll_top as i32);
+            }
+        }
+        let measured = match self.items_ref.cast::<Element>() {
+            Some(items) => self.viewport.measure(self.window.start, child_heights(&items).into_iter()),
+            None => false,
+        };
+        if first_render || measured {
+            self.link.send_message(VirtualListMsg::Scroll);
+        }
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = Classes::new();
+        classes.push(&self.props.classes);
+        let items = self.props.items[self.window.start..self.window.end]
+            .iter()
+            .map(|item| html! {<div>{self.props.render.render(item)}</div>})
+            .collect::<Html>();
+        html! {
+            <div
+                ref=self.container_ref.clone()
+                class=classes
+                style=format!("max-height:{};overflow-y:auto;", self.props.height)
+                onscroll=self.link.callback(|_| VirtualListMsg::Scroll)
+            >
+                <div style=format!("height:{}px;", self.window.before)></div>
+                <div ref=self.items_ref.clone()>
+                    {items}
+                </div>
+                <div style=format!("height:{}px;", self.window.after)></div>
+            </div>
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::{CellEditor, FilterKind};
+use crate::Alignment;
+
+/// The definition of a single column of a `DataTable`.
+///
+/// A column is built from its ID, header & cell renderer, along with any of the optional settings
+/// provided by the builder methods of this type.
+///
+/// ```rust
+/// use ybc::{Alignment, TableColumn};
+/// use yew::prelude::*;
+///
+/// #[derive(Clone, PartialEq)]
+/// struct Item {
+///     price: u32,
+/// }
+///
+/// let column = TableColumn::new("price", html! {"Price"}, |item: &Item| html! {item.price})
+///     .alignment(Alignment::Right)
+///     .width("8em")
+///     .sort_by_key(|item: &Item| item.price)
+///     .footer(|items: &[Item]| html! {items.iter().map(|item| item.price).sum::<u32>()});
+/// ```
+pub struct TableColumn<T> {
+    /// The ID of this column, unique within its table.
+    pub id: String,
+    /// The contents of this column's header cell.
+    pub header: Html,
+    /// The alignment of this column's cells.
+    pub alignment: Option<Alignment>,
+    /// The width of this column, as a CSS length.
+    pub width: Option<String>,
+    cell: Rc<dyn Fn(&T) -> Html>,
+    footer: Option<FooterFn<T>>,
+    compare: Option<CompareFn<T>>,
+    value: Option<ValueFn<T>>,
+    filter: Option<FilterKind>,
+    editor: Option<CellEditor>,
+    validate: Option<ValidateFn>,
+    title: Option<String>,
+    export: Option<ValueFn<T>>,
+}
+
+type FooterFn<T> = Rc<dyn Fn(&[T]) -> Html>;
+type CompareFn<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+type ValueFn<T> = Rc<dyn Fn(&T) -> String>;
+type ValidateFn = Rc<dyn Fn(&str) -> Result<(), String>>;
+
+impl<T> TableColumn<T> {
+    /// Create a new column with the given ID, header & cell renderer.
+    pub fn new(id: impl Into<String>, header: Html, cell: impl Fn(&T) -> Html + 'static) -> Self {
+        Self {
+            id: id.into(),
+            header,
+            alignment: None,
+            width: None,
+            cell: Rc::new(cell),
+            footer: None,
+            compare: None,
+            value: None,
+            filter: None,
+            editor: None,
+            validate: None,
+            title: None,
+            export: None,
+        }
+    }
+
+    /// Set the alignment of this column's cells.
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Set the width of this column, as a CSS length.
+    pub fn width(mut self, width: impl Into<String>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// Add a footer to this column, rendered from all of the rows of the table; typically an
+    /// aggregate such as a sum or a count.
+    pub fn footer(mut self, footer: impl Fn(&[T]) -> Html + 'static) -> Self {
+        self.footer = Some(Rc::new(footer));
+        self
+    }
+
+    /// Make this column sortable, ordering rows with the given comparator.
+    pub fn sortable(mut self, compare: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    /// Make this column sortable, ordering rows by the key extracted with the given function.
+    pub fn sort_by_key<K: Ord>(self, key: impl Fn(&T) -> K + 'static) -> Self {
+        self.sortable(move |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Set the plain-text value of this column's cells, used for searching & filtering rows.
+    pub fn value(mut self, value: impl Fn(&T) -> String + 'static) -> Self {
+        self.value = Some(Rc::new(value));
+        self
+    }
+
+    /// Render a filter control of the given kind for this column.
+    ///
+    /// Filters are applied to the plain-text values of the column, so the column must also have
+    /// a `value`; otherwise the filter matches all rows.
+    pub fn filter(mut self, kind: FilterKind) -> Self {
+        self.filter = Some(kind);
+        self
+    }
+
+    /// Set the plain-text title of this column, used in place of its ID when exporting rows.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the formatter of this column's cells when exporting rows; defaults to the `value` of
+    /// the column.
+    pub fn export(mut self, export: impl Fn(&T) -> String + 'static) -> Self {
+        self.export = Some(Rc::new(export));
+        self
+    }
+
+    /// Make the cells of this column editable with the given form control.
+    ///
+    /// Editing starts with the plain-text value of the cell, so the column must also have a
+    /// `value`. Committed edits are propagated via the table's `onedit` callback.
+    pub fn editable(mut self, editor: CellEditor) -> Self {
+        self.editor = Some(editor);
+        self
+    }
+
+    /// Validate the values of edited cells of this column, returning an error message for values
+    /// which are not valid. Invalid values are not committed.
+    pub fn validate(mut self, validate: impl Fn(&str) -> Result<(), String> + 'static) -> Self {
+        self.validate = Some(Rc::new(validate));
+        self
+    }
+
+    /// The form control used for editing the cells of this column, if editable.
+    pub fn editor(&self) -> Option<&CellEditor> {
+        self.editor.as_ref()
+    }
+
+    /// The kind of filter control of this column, if any.
+    pub fn filter_kind(&self) -> Option<&FilterKind> {
+        self.filter.as_ref()
+    }
+
+    /// Check if this column is sortable.
+    pub fn is_sortable(&self) -> bool {
+        self.compare.is_some()
+    }
+
+    /// The comparator of this column, if it is sortable.
+    pub(crate) fn comparator(&self) -> Option<&CompareFn<T>> {
+        self.compare.as_ref()
+    }
+
+    /// Render this column's cell for the given row.
+    pub(crate) fn render_cell(&self, row: &T) -> Html {
+        (self.cell)(row)
+    }
+
+    /// The plain-text title of this column.
+    pub(crate) fn plain_title(&self) -> &str {
+        self.title.as_deref().unwrap_or(&self.id)
+    }
+
+    /// Render the exported value of this column for the given row.
+    pub(crate) fn render_export(&self, row: &T) -> String {
+        match &self.export {
+            Some(export) => export(row),
+            None => self.render_value(row).unwrap_or_default(),
+        }
+    }
+
+    /// Validate the given value of an edited cell of this column.
+    pub(crate) fn validate_value(&self, value: &str) -> Result<(), String> {
+        self.validate.as_ref().map(|validate| validate(value)).unwrap_or(Ok(()))
+    }
+
+    /// Render the plain-text value of this column for the given row, if this column has a value.
+    pub(crate) fn render_value(&self, row: &T) -> Option<String> {
+        self.value.as_ref().map(|value| value(row))
+    }
+
+    /// Check if this column has a footer.
+    pub(crate) fn has_footer(&self) -> bool {
+        self.footer.is_some()
+    }
+
+    /// Render this column's footer for the given rows, if this column has a footer.
+    pub(crate) fn render_footer(&self, rows: &[T]) -> Option<Html> {
+        self.footer.as_ref().map(|footer| footer(rows))
+    }
+
+    /// The class used for aligning the text of this column's cells.
+    pub(crate) fn alignment_class(&self) -> Option<&'static str> {
+        self.alignment.as_ref().map(|alignment| match alignment {
+            Alignment::Left => "has-text-left",
+            Alignment::Centered => "has-text-centered",
+            Alignment::Right => "has-text-right",
+        })
+    }
+}
+
+impl<T> Clone for TableColumn<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            header: self.header.clone(),
+            alignment: self.alignment.clone(),
+            width: self.width.clone(),
+            cell: self.cell.clone(),
+            footer: self.footer.clone(),
+            compare: self.compare.clone(),
+            value: self.value.clone(),
+            filter: self.filter.clone(),
+            editor: self.editor.clone(),
+            validate: self.validate.clone(),
+            title: self.title.clone(),
+            export: self.export.clone(),
+        }
+    }
+}
+
+/// Columns are equal when their settings are equal & they share the same renderer closures.
+impl<T> PartialEq for TableColumn<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.header == other.header
+            && self.alignment == other.alignment
+            && self.width == other.width
+            && Rc::ptr_eq(&self.cell, &other.cell)
+            && opt_ptr_eq(&self.footer, &other.footer)
+            && self.filter == other.filter
+            && self.editor == other.editor
+            && opt_ptr_eq(&self.validate, &other.validate)
+            && self.title == other.title
+            && opt_ptr_eq(&self.export, &other.export)
+            && opt_ptr_eq(&self.compare, &other.compare)
+            && opt_ptr_eq(&self.value, &other.value)
+    }
+}
+
+/// Compare two optional closures by pointer.
+fn opt_ptr_eq<F: ?Sized>(a: &Option<Rc<F>>, b: &Option<Rc<F>>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+impl<T> fmt::Debug for TableColumn<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TableColumn")
+            .field("id", &self.id)
+            .field("alignment", &self.alignment)
+            .field("width", &self.width)
+            .field("sortable", &self.is_sortable())
+            .field("filter", &self.filter)
+            .field("editor", &self.editor)
+            .finish()
+    }
+}
@@ -0,0 +1,44 @@
+/// The form control used for editing the cells of a `DataTable` column.
+///
+/// Editors are given the plain-text value of the cell, as given by `TableColumn::value`, and
+/// produce the new plain-text value of the cell.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CellEditor {
+    /// An `Input` for a single line of text.
+    Input,
+    /// A `TextArea` for multiple lines of text; `Shift+Enter` inserts a line break.
+    TextArea,
+    /// A `Select` of the given `(value, label)` options.
+    Select(Vec<(String, String)>),
+    /// A `Checkbox`, whose values are `"true"` & `"false"`.
+    Checkbox,
+}
+
+/// An edit of a cell of a `DataTable`, committed by the user.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CellEdit<K> {
+    /// The key of the edited row.
+    pub key: K,
+    /// The ID of the edited column.
+    pub column: String,
+    /// The new plain-text value of the cell.
+    pub value: String,
+}
+
+/// The state of the cell being edited.
+pub(crate) struct Editing<K> {
+    pub key: K,
+    pub column: String,
+    pub value: String,
+    /// The validation error of the current value, shown once the user attempts to commit it.
+    pub error: Option<String>,
+}
+
+/// The element to focus after the next render, following a change of the cell being edited.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum EditFocus {
+    /// Focus the form control of the cell being edited.
+    Editor,
+    /// Return focus to the cell which was edited.
+    Cell,
+}
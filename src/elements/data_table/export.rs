@@ -0,0 +1,165 @@
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+use super::TableColumn;
+
+/// The format of exported table data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma-separated values, as per RFC 4180.
+    Csv,
+    /// Tab-separated values, quoted in the same manner as CSV.
+    Tsv,
+}
+
+impl ExportFormat {
+    /// The field delimiter of this format.
+    pub fn delimiter(self) -> char {
+        match self {
+            ExportFormat::Csv => ',',
+            ExportFormat::Tsv => '\t',
+        }
+    }
+
+    /// The MIME type of this format.
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Tsv => "text/tab-separated-values",
+        }
+    }
+
+    /// The file extension of this format, without the leading dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Tsv => "tsv",
+        }
+    }
+}
+
+/// Serialise the given records in the given format.
+///
+/// Fields containing the delimiter, a double quote or a line break are enclosed in double quotes,
+/// with any double quotes doubled. Records are terminated by `CRLF`.
+///
+/// ```
+/// use ybc::{write_delimited, ExportFormat};
+///
+/// let records = vec![vec!["name", "note"], vec!["Ada", "says \"hi\", twice"]];
+/// let csv = write_delimited(ExportFormat::Csv, records);
+/// assert_eq!(csv, "name,note\r\nAda,\"says \"\"hi\"\", twice\"\r\n");
+/// ```
+pub fn write_delimited<R, F>(format: ExportFormat, records: impl IntoIterator<Item = R>) -> String
+where
+    R: IntoIterator<Item = F>,
+    F: AsRef<str>,
+{
+    let delimiter = format.delimiter();
+    let mut out = String::new();
+    for record in records {
+        for (idx, field) in record.into_iter().enumerate() {
+            if idx > 0 {
+                out.push(delimiter);
+            }
+            let field = field.as_ref();
+            if field.contains([delimiter, '"', '\n', '\r']) {
+                out.push('"');
+                out.push_str(&field.replace('"', "\"\""));
+                out.push('"');
+            } else {
+                out.push_str(field);
+            }
+        }
+        out.push_str("\r\n");
+    }
+    out
+}
+
+/// Serialise the given rows in the given format, with a header record of the titles of the given
+/// columns followed by a record of the export values of each row.
+pub fn export_rows<'a, T: 'a>(format: ExportFormat, columns: &[&TableColumn<T>], rows: impl IntoIterator<Item = &'a T>) -> String {
+    let header = columns
+        .iter()
+        .map(|column| column.plain_title().to_string())
+        .collect::<Vec<_>>();
+    let records = rows
+        .into_iter()
+        .map(|row| columns.iter().map(|column| column.render_export(row)).collect::<Vec<_>>());
+    write_delimited(format, std::iter::once(header).chain(records))
+}
+
+/// Trigger a browser download of a file with the given name, MIME type & text content.
+pub fn download_text(filename: &str, mime_type: &str, content: &str) -> Result<(), JsValue> {
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| JsValue::from_str("no document"))?;
+    let parts = js_sys::Array::of1(&JsValue::from_str(content));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+    let anchor = document.create_element("a")?.dyn_into::<HtmlAnchorElement>()?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    // Some browsers ignore clicks on detached anchors, or downloads whose URL is revoked before
+    // the download starts; the URL is therefore only revoked in the next task.
+    let body = document.body().ok_or_else(|| JsValue::from_str("no body"))?;
+    body.append_child(&anchor)?;
+    anchor.click();
+    body.remove_child(&anchor)?;
+    let revoke = Closure::once_into_js(move || {
+        let _ = Url::revoke_object_url(&url);
+    });
+    web_sys::window()
+        .ok_or_else(|| JsValue::from_str("no window"))?
+        .set_timeout_with_callback(revoke.unchecked_ref())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yew::html;
+
+    #[test]
+    fn plain_fields_are_not_quoted() {
+        let out = write_delimited(ExportFormat::Csv, vec![vec!["a", "b c", ""], vec!["1", "2", "3"]]);
+        assert_eq!(out, "a,b c,\r\n1,2,3\r\n");
+    }
+
+    #[test]
+    fn special_fields_are_quoted() {
+        let out = write_delimited(ExportFormat::Csv, vec![vec!["a,b", "say \"hi\"", "line\nbreak", "cr\r"]]);
+        assert_eq!(out, "\"a,b\",\"say \"\"hi\"\"\",\"line\nbreak\",\"cr\r\"\r\n");
+    }
+
+    #[test]
+    fn tsv_quotes_tabs_but_not_commas() {
+        let out = write_delimited(ExportFormat::Tsv, vec![vec!["a,b", "c\td"]]);
+        assert_eq!(out, "a,b\t\"c\td\"\r\n");
+    }
+
+    #[test]
+    fn no_records_is_empty() {
+        let out = write_delimited(ExportFormat::Csv, Vec::<Vec<&str>>::new());
+        assert_eq!(out, "");
+    }
+
+    #[test]
+    fn rows_are_exported_with_titles_and_formatters() {
+        struct Row {
+            name: &'static str,
+            price: f64,
+        }
+        let name = TableColumn::new("name", html! {"Name"}, |row: &Row| html! {row.name}).value(|row| row.name.to_string());
+        let price = TableColumn::new("price", html! {"Price"}, |row: &Row| html! {row.price})
+            .title("Price (EUR)")
+            .value(|row| row.price.to_string())
+            .export(|row| format!("{:.2}", row.price));
+        let rows = vec![Row { name: "Tea, green", price: 2.5 }, Row { name: "Coffee", price: 3.0 }];
+        let out = export_rows(ExportFormat::Csv, &[&name, &price], &rows);
+        assert_eq!(out, "name,Price (EUR)\r\n\"Tea, green\",2.50\r\nCoffee,3.00\r\n");
+    }
+}
@@ -0,0 +1,103 @@
+use super::{SortKey, TableColumn};
+
+/// The kind of filter control rendered for a `DataTable` column.
+///
+/// Filters are applied to the plain-text values of a column's cells, as given by
+/// `TableColumn::value`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FilterKind {
+    /// A text input, matching rows whose value contains the text, ignoring case.
+    Text,
+    /// A select of the distinct values of the column, matching rows with the selected value.
+    Select,
+    /// A pair of inputs for the lower & upper bounds of a numeric value, both inclusive.
+    Range,
+}
+
+/// The value of a `DataTable` column filter.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FilterValue {
+    /// Match rows whose value contains the text, ignoring case.
+    Text(String),
+    /// Match rows whose value is equal to the selected value.
+    Select(String),
+    /// Match rows whose value, parsed as a number, lies within the bounds.
+    Range { min: Option<f64>, max: Option<f64> },
+}
+
+impl FilterValue {
+    /// Check if this filter matches all rows.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            FilterValue::Text(text) | FilterValue::Select(text) => text.is_empty(),
+            FilterValue::Range { min, max } => min.is_none() && max.is_none(),
+        }
+    }
+
+    /// Check if the given cell value matches this filter.
+    pub fn matches(&self, value: &str) -> bool {
+        match self {
+            FilterValue::Text(text) => contains_ignore_case(value, text),
+            FilterValue::Select(selected) => selected.is_empty() || value == selected,
+            FilterValue::Range { min, max } => match value.trim().parse::<f64>() {
+                Ok(value) => min.map(|min| value >= min).unwrap_or(true) && max.map(|max| value <= max).unwrap_or(true),
+                Err(_) => self.is_empty(),
+            },
+        }
+    }
+}
+
+/// The effective query of a `DataTable`, being the search, filters, sort & page of its rows.
+///
+/// For tables whose rows are provided by a server, this describes the rows to be requested.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableQuery {
+    /// The global search text; matching rows with any column value containing the text.
+    pub search: String,
+    /// The active column filters, as pairs of the column ID & the filter value.
+    pub filters: Vec<(String, FilterValue)>,
+    /// The sort state, in order of priority.
+    pub sort: Vec<SortKey>,
+    /// The zero-based index of the current page.
+    pub page: usize,
+    /// The number of rows per page, if the rows are paged.
+    pub page_size: Option<usize>,
+}
+
+impl TableQuery {
+    /// Check if the given row matches the search & the filters of this query.
+    pub(crate) fn matches<T>(&self, row: &T, columns: &[TableColumn<T>]) -> bool {
+        let search = self.search.trim();
+        if !search.is_empty() {
+            let found = columns
+                .iter()
+                .filter_map(|column| column.render_value(row))
+                .any(|value| contains_ignore_case(&value, search));
+            if !found {
+                return false;
+            }
+        }
+        self.filters.iter().all(|(id, filter)| {
+            match columns
+                .iter()
+                .find(|column| &column.id == id)
+                .and_then(|column| column.render_value(row))
+            {
+                Some(value) => filter.matches(&value),
+                None => true,
+            }
+        })
+    }
+}
+
+/// The sorted, distinct values of the given column, for use as the options of a select filter.
+pub(crate) fn distinct_values<T>(rows: &[T], column: &TableColumn<T>) -> Vec<String> {
+    let mut values = rows.iter().filter_map(|row| column.render_value(row)).collect::<Vec<_>>();
+    values.sort();
+    values.dedup();
+    values
+}
+
+fn contains_ignore_case(value: &str, text: &str) -> bool {
+    value.to_lowercase().contains(&text.to_lowercase())
+}
@@ -0,0 +1,87 @@
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The side of a `DataTable` to which a column is pinned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ColumnPin {
+    Left,
+    Right,
+}
+
+/// The user-customised layout of the columns of a `DataTable`.
+///
+/// Columns are referred to by their IDs, so that layouts remain valid as columns are added to or
+/// removed from a table. With the `serde` feature enabled, layouts can be serialised for
+/// persisting them across sessions.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TableLayout {
+    /// The IDs of the columns in display order; columns not listed follow in their defined order.
+    pub order: Vec<String>,
+    /// The IDs of the hidden columns.
+    pub hidden: Vec<String>,
+    /// The widths of resized columns in pixels, by column ID.
+    pub widths: BTreeMap<String, f64>,
+    /// The sides to which pinned columns are pinned, by column ID.
+    pub pinned: BTreeMap<String, ColumnPin>,
+}
+
+impl TableLayout {
+    /// Arrange the columns with the given IDs, returning the indices of the visible columns in
+    /// display order: the columns pinned to the left, then the unpinned columns, then the columns
+    /// pinned to the right.
+    pub(crate) fn arrange<'a>(&self, ids: impl Iterator<Item = &'a str>) -> Vec<usize> {
+        let mut columns = ids
+            .enumerate()
+            .filter(|(_, id)| !self.hidden.iter().any(|hidden| hidden == id))
+            .map(|(idx, id)| {
+                let position = self.order.iter().position(|ordered| ordered == id).unwrap_or(usize::MAX);
+                let group = match self.pinned.get(id) {
+                    Some(ColumnPin::Left) => 0,
+                    None => 1,
+                    Some(ColumnPin::Right) => 2,
+                };
+                (group, position, idx)
+            })
+            .collect::<Vec<_>>();
+        columns.sort_unstable();
+        columns.into_iter().map(|(_, _, idx)| idx).collect()
+    }
+
+    /// Move the column `from` to the position of the column `to`, given the IDs of all columns in
+    /// display order.
+    pub(crate) fn move_column(&mut self, ids: Vec<String>, from: &str, to: &str) {
+        let mut order = ids;
+        let (from_idx, to_idx) = match (order.iter().position(|id| id == from), order.iter().position(|id| id == to)) {
+            (Some(from_idx), Some(to_idx)) if from_idx != to_idx => (from_idx, to_idx),
+            _ => return,
+        };
+        let id = order.remove(from_idx);
+        order.insert(to_idx, id);
+        self.order = order;
+    }
+
+    /// Show or hide the column with the given ID.
+    pub(crate) fn set_hidden(&mut self, id: &str, hidden: bool) {
+        self.hidden.retain(|hidden| hidden != id);
+        if hidden {
+            self.hidden.push(id.to_string());
+        }
+    }
+
+    /// Pin the column with the given ID to the given side, or unpin it.
+    pub(crate) fn set_pinned(&mut self, id: &str, pin: Option<ColumnPin>) {
+        match pin {
+            Some(pin) => {
+                self.pinned.insert(id.to_string(), pin);
+            }
+            None => {
+                self.pinned.remove(id);
+            }
+        }
+    }
+}
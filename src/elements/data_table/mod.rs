@@ -0,0 +1,1843 @@
+#![allow(clippy::unnecessary_operation)]
+
+mod column;
+mod edit;
+mod export;
+mod filter;
+mod layout;
+mod row;
+mod sort;
+mod tree;
+
+pub use column::TableColumn;
+pub use edit::{CellEdit, CellEditor};
+pub use export::{download_text, export_rows, write_delimited, ExportFormat};
+pub use filter::{FilterKind, FilterValue, TableQuery};
+pub use layout::{ColumnPin, TableLayout};
+pub use row::RowKey;
+pub use sort::{SortDirection, SortKey};
+pub use tree::RowTree;
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use gloo_events::EventListener;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+use yew::services::resize::{ResizeService, ResizeTask};
+use yewtil::NeqAssign;
+
+use crate::components::dropdown::Dropdown;
+use crate::components::pagination::{Pagination, PaginationEllipsis, PaginationItem, PaginationItemType};
+use crate::components::virtual_list::{ItemRenderer, RowHeight, Viewport, Window};
+use crate::elements::button::Button;
+use crate::elements::icon::Icon;
+use crate::elements::table::{table_classes, TableStyle};
+use crate::form::checkbox::Checkbox;
+use crate::form::control::Control;
+use crate::form::input::Input;
+use crate::form::select::Select;
+use crate::form::textarea::TextArea;
+use crate::Size;
+use edit::{EditFocus, Editing};
+
+/// The minimum width of resized columns, in pixels.
+const MIN_COLUMN_WIDTH: f64 = 40.0;
+/// The change in width of columns resized via the keyboard, in pixels.
+const RESIZE_STEP: f64 = 10.0;
+
+/// The message type used by the `DataTable` component.
+pub enum DataTableMsg {
+    /// Cycle the sort direction of the column with the given ID; retaining the other sort keys
+    /// when `multi` is `true`.
+    Sort { column: String, multi: bool },
+    /// Toggle the selection of the row at the given display position.
+    ToggleRow(usize),
+    /// Select all rows, or clear the selection if all rows are already selected.
+    ToggleAll,
+    /// Record whether `Shift` is held as a row's checkbox is pressed, for range selection.
+    Shift(bool),
+    /// The scroll area of a virtualized table was scrolled or resized.
+    Scroll,
+    /// Update the global search text.
+    Search(String),
+    /// Update the filter of the column with the given ID.
+    Filter { column: String, value: FilterValue },
+    /// Update the text of a bound of the range filter of the column with the given ID.
+    FilterRange { column: String, upper: bool, text: String },
+    /// Go to the page with the given zero-based index.
+    Page(usize),
+    /// Expand or collapse the row at the given display position.
+    Expand { position: usize, expanded: bool },
+    /// Start editing the cell of the given column of the row at the given display position.
+    Edit { position: usize, column: String },
+    /// Update the value of the cell being edited.
+    EditValue(String),
+    /// Validate & commit the value of the cell being edited.
+    CommitEdit,
+    /// Stop editing the cell being edited, discarding its value.
+    CancelEdit,
+    /// Start resizing the column with the given ID from its given width, at the given cursor position.
+    ResizeStart { column: String, x: f64, width: f64 },
+    /// Resize the column being resized, following the cursor to the given position.
+    ResizeMove(f64),
+    /// Stop resizing the column being resized.
+    ResizeEnd,
+    /// Resize the column with the given ID to the given width, in pixels.
+    Resize { column: String, width: f64 },
+    /// Start dragging the header of the column with the given ID.
+    DragStart(String),
+    /// Drop the dragged header onto the header of the column with the given ID.
+    Drop(String),
+    /// Show or hide the column with the given ID.
+    SetHidden { column: String, hidden: bool },
+    /// Pin the column with the given ID to the given side, or unpin it.
+    SetPinned { column: String, pin: Option<ColumnPin> },
+    /// Stop dragging the dragged header.
+    DragEnd,
+    /// Download the displayed rows & the visible columns in the given format.
+    Export(ExportFormat),
+    /// Measure the widths of the header cells, for positioning pinned columns.
+    Measure,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct DataTableProps<T: Clone + PartialEq + 'static, K: Clone + Eq + Hash + 'static> {
+    /// The rows of this table.
+    pub rows: Vec<T>,
+    /// The columns of this table.
+    pub columns: Vec<TableColumn<T>>,
+    /// The content to display in place of the rows when there are no rows.
+    #[prop_or_default]
+    pub empty: Html,
+
+    /// The sort state of this table, in order of priority.
+    #[prop_or_default]
+    pub sort: Vec<SortKey>,
+    /// The callback to be used for propagating changes to the sort state of this table.
+    #[prop_or_else(Callback::noop)]
+    pub onsort: Callback<Vec<SortKey>>,
+    /// Display the rows as given, as they are searched, filtered, sorted & paged by a server.
+    ///
+    /// Changes to the query are still reflected in the table & propagated via the `onsort` &
+    /// `onquery` callbacks, so that the rows can be requested from the server accordingly.
+    #[prop_or_default]
+    pub server: bool,
+    /// The content of the sort indicator of columns sorted in ascending order.
+    #[prop_or_else(|| html! {"\u{25b2}"})]
+    pub sort_asc_html: Html,
+    /// The content of the sort indicator of columns sorted in descending order.
+    #[prop_or_else(|| html! {"\u{25bc}"})]
+    pub sort_desc_html: Html,
+
+    /// The function extracting the key of each row, required for selecting rows.
+    #[prop_or_default]
+    pub row_key: Option<RowKey<T, K>>,
+    /// Render a checkbox for selecting each row, along with a checkbox for selecting all rows.
+    #[prop_or_default]
+    pub selectable: bool,
+    /// The keys of the selected rows.
+    #[prop_or_default]
+    pub selection: Vec<K>,
+    /// The callback to be used for propagating changes to the keys of the selected rows.
+    #[prop_or_else(Callback::noop)]
+    pub onselection: Callback<Vec<K>>,
+    /// The bulk actions toolbar, displayed above the table while any rows are selected.
+    #[prop_or_default]
+    pub bulk_actions: Html,
+
+    /// The function rendering the detail region of each row, displayed beneath the row while it
+    /// is expanded. Requires a `row_key`.
+    #[prop_or_default]
+    pub detail: Option<ItemRenderer<T>>,
+    /// The hierarchy of the rows, displaying the table as a tree. Requires a `row_key`.
+    #[prop_or_default]
+    pub tree: Option<RowTree<T, K>>,
+    /// The keys of the expanded rows.
+    #[prop_or_default]
+    pub expanded: Vec<K>,
+    /// The callback to be used for propagating the key of each row as it is expanded.
+    #[prop_or_else(Callback::noop)]
+    pub onexpand: Callback<K>,
+    /// The callback to be used for propagating the key of each row as it is collapsed.
+    #[prop_or_else(Callback::noop)]
+    pub oncollapse: Callback<K>,
+    /// The content of the toggle of collapsed rows.
+    #[prop_or_else(|| html! {"\u{25b8}"})]
+    pub expand_html: Html,
+    /// The content of the toggle of expanded rows.
+    #[prop_or_else(|| html! {"\u{25be}"})]
+    pub collapse_html: Html,
+    /// The content displayed beneath an expanded row while its children are loaded lazily.
+    #[prop_or_else(|| html! {<progress class="progress is-small" max="100"></progress>})]
+    pub loading_html: Html,
+
+    /// The callback to be used for propagating the edits of cells, once committed. Requires a
+    /// `row_key`.
+    #[prop_or_else(Callback::noop)]
+    pub onedit: Callback<CellEdit<K>>,
+    /// The dirty cells, as pairs of the row key & the column ID, highlighted until saved.
+    ///
+    /// Cells are also marked as dirty as their edits are committed, until this prop changes.
+    #[prop_or_default]
+    pub dirty: Vec<(K, String)>,
+
+    /// The layout of the columns of this table.
+    #[prop_or_default]
+    pub layout: TableLayout,
+    /// The callback to be used for propagating changes to the layout of the columns of this table.
+    #[prop_or_else(Callback::noop)]
+    pub onlayout: Callback<TableLayout>,
+    /// Allow the columns to be resized, reordered, hidden & pinned by the user.
+    #[prop_or_default]
+    pub customizable: bool,
+    /// The content of the trigger button of the column chooser of a customizable table.
+    #[prop_or_else(|| html! {"Columns"})]
+    pub columns_html: Html,
+
+    /// The formats for which to render an export button above the table.
+    #[prop_or_default]
+    pub exports: Vec<ExportFormat>,
+    /// The name of exported files, without the extension.
+    #[prop_or_else(|| "export".into())]
+    pub export_filename: String,
+
+    /// Render a global search input above the table, matching the values of all columns.
+    #[prop_or_default]
+    pub searchable: bool,
+    /// The placeholder of the global search input.
+    #[prop_or_else(|| "Search".into())]
+    pub search_placeholder: String,
+    /// The number of rows per page; the rows are not paged if `None`.
+    #[prop_or_default]
+    pub page_size: Option<usize>,
+    /// The total number of rows matching the query, for paging the rows of a server-side table.
+    ///
+    /// Defaults to the number of rows given.
+    #[prop_or_default]
+    pub total_rows: Option<usize>,
+    /// The callback to be used for propagating changes to the effective query of this table.
+    #[prop_or_else(Callback::noop)]
+    pub onquery: Callback<TableQuery>,
+
+    #[prop_or_default]
+    pub classes: Option<Classes>,
+    /// Add borders to all the cells.
+    #[prop_or_default]
+    pub bordered: bool,
+    /// Add stripes to the table.
+    #[prop_or_default]
+    pub striped: bool,
+    /// Make the cells narrower.
+    #[prop_or_default]
+    pub narrow: bool,
+    /// Add a hover effect on each row.
+    #[prop_or_default]
+    pub hoverable: bool,
+    /// Make the table fullwidth.
+    #[prop_or_default]
+    pub fullwidth: bool,
+    /// Make the table scrollable, wrapping the table in a `div.table-container`.
+    #[prop_or_default]
+    pub scrollable: bool,
+
+    /// Only render the rows within view of the table's scroll area, with rows of the given height.
+    ///
+    /// The table is rendered within a vertically scrollable `div.table-container`, with a sticky
+    /// header. This is recommended for tables of many thousands of rows.
+    #[prop_or_default]
+    pub virtualize: Option<RowHeight>,
+    /// The maximum height of the scroll area of a virtualized table, as a CSS length.
+    #[prop_or_else(|| "400px".into())]
+    pub viewport_height: String,
+    /// The number of extra rows to render before & after the visible rows of a virtualized table.
+    #[prop_or_else(|| 5)]
+    pub overscan: usize,
+}
+
+/// A table rendered from a list of rows & a list of typed column definitions.
+///
+/// [https://bulma.io/documentation/elements/table/](https://bulma.io/documentation/elements/table/)
+///
+/// Each `TableColumn` renders its header cell, the cell of each row and optionally a footer cell
+/// which is rendered from all of the rows matching the filters. The table footer is only rendered
+/// when at least one of the columns has a footer.
+///
+/// The headers of sortable columns cycle the column through ascending, descending & unsorted
+/// order when clicked. Holding `Shift` while clicking adds the column to the current sort state,
+/// instead of replacing it, for sorting by multiple columns.
+///
+/// Columns with a `filter` render a filter control beneath their header, and the `searchable`
+/// prop adds a global search input above the table. With a `page_size`, the rows are paged with a
+/// `Pagination` beneath the table. Rows are filtered, then sorted, then paged; each change of the
+/// effective query is propagated via the `onquery` callback. When the `server` prop is set, the
+/// rows are displayed as given & only the query is updated.
+///
+/// When the `selectable` prop is set & a `row_key` is given, each row can be selected via its
+/// checkbox, and the checkbox in the header selects all rows which match the filters, on every
+/// page. Holding `Shift` while pressing a row's checkbox applies the change to all rows between it
+/// & the previously toggled row. The keys of the selected rows are propagated via the
+/// `onselection` callback, in display order.
+///
+/// With a `detail` renderer or a `tree` hierarchy, the first cell of each expandable row gets a
+/// toggle, which also responds to the `Right` & `Left` arrow keys for expanding & collapsing the
+/// row. Expanded rows display their detail region beneath them, and the child rows of a tree table
+/// are indented by their depth; see `RowTree` for loading child rows lazily.
+///
+/// The cells of `editable` columns switch to their form control when double-clicked, or when
+/// `Enter` is pressed while the cell is focused. Edits are committed with `Enter` or by moving
+/// focus away from the cell, once the value passes the column's validation, and discarded with
+/// `Escape`. Committed edits which change the value of a cell are propagated via the `onedit`
+/// callback & the cell is highlighted as dirty.
+///
+/// The `layout` prop orders, hides, sizes & pins the columns; see `TableLayout`. Pinned columns
+/// stick to the side of the table's scroll area, so the table should be `scrollable` or
+/// virtualized. With the `customizable` prop set, the columns can be resized by dragging the right
+/// border of their header, or with the arrow keys while the border is focused, and reordered by
+/// dragging their header onto another header. A column chooser `Dropdown` above the table shows,
+/// hides & pins the columns. Each change of the layout is propagated via the `onlayout` callback.
+///
+/// For each of the `exports` formats, an export button above the table downloads the rows which
+/// match the filters, in display order & across all pages, with the visible columns; see
+/// `export_rows`.
+///
+/// With the `virtualize` prop set, only the rows within view are rendered; see `RowHeight` for
+/// how the heights of the rows are determined. The scroll position is preserved as the rows change.
+pub struct DataTable<T: Clone + PartialEq + 'static, K: Clone + Eq + Hash + 'static = ()> {
+    link: ComponentLink<Self>,
+    props: DataTableProps<T, K>,
+    /// The current sort state.
+    sort: Vec<SortKey>,
+    /// The global search text.
+    search: String,
+    /// The active column filters, by column ID.
+    filters: Vec<(String, FilterValue)>,
+    /// The text of the lower & upper bound inputs of range filters, by column ID.
+    range_inputs: HashMap<String, (String, String)>,
+    /// The distinct values of the columns with select filters, by column ID.
+    options: HashMap<String, Vec<String>>,
+    /// The zero-based index of the current page.
+    page: usize,
+    /// The indices of the rows which match the filters, in the order in which they are displayed.
+    order: Vec<usize>,
+    /// The positions of the displayed rows within the hierarchy of a tree table, by display position.
+    tree_rows: Vec<tree::TreeRow>,
+    /// The keys of the expanded rows.
+    expanded: HashSet<K>,
+    /// The cell being edited.
+    editing: Option<Editing<K>>,
+    /// The cell which was most recently edited, as the row key & column ID.
+    edited: Option<(K, String)>,
+    /// The dirty cells, as pairs of the row key & the column ID.
+    dirty: HashSet<(K, String)>,
+    /// The element to focus after the next render.
+    edit_focus: Option<EditFocus>,
+    /// The cell being edited, or most recently edited.
+    edit_ref: NodeRef,
+    /// The `focusout` listener of the cell being edited, committing the edit as focus leaves it.
+    edit_listener: Option<EventListener>,
+    /// The current layout of the columns.
+    layout: TableLayout,
+    /// The column being resized, along with the cursor position & its width as resizing started.
+    resizing: Option<(String, f64, f64)>,
+    /// The document-level `mousemove` & `mouseup` listeners of the column being resized.
+    resize_listeners: Vec<EventListener>,
+    /// The ID of the column whose header is being dragged.
+    dragging: Option<String>,
+    head_ref: NodeRef,
+    /// The measured widths of the header cells, including that of the selection column.
+    head_widths: Vec<f64>,
+    /// The keys of the rows, by row index.
+    keys: Vec<K>,
+    /// The keys of the selected rows.
+    selected: HashSet<K>,
+    /// The display position of the most recently toggled row, used as the start of range selections.
+    anchor: Option<usize>,
+    /// Whether `Shift` was held as the most recent row checkbox was pressed.
+    shift: bool,
+    viewport: Viewport,
+    /// The range of rows rendered by a virtualized table.
+    window: Window,
+    container_ref: NodeRef,
+    body_ref: NodeRef,
+    /// A flag indicating that the scroll position should be restored after the next render.
+    restore_scroll: bool,
+    #[allow(dead_code)]
+    resize_task: Option<ResizeTask>,
+}
+
+impl<T: Clone + PartialEq + 'static, K: Clone + Eq + Hash + 'static> Component for DataTable<T, K> {
+    type Message = DataTableMsg;
+    type Properties = DataTableProps<T, K>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let sort = props.sort.clone();
+        let selected = props.selection.iter().cloned().collect();
+        let expanded = props.expanded.iter().cloned().collect();
+        let dirty = props.dirty.iter().cloned().collect();
+        let layout = props.layout.clone();
+        let viewport = Viewport::new(props.virtualize.clone().unwrap_or(RowHeight::Fixed(0.0)), props.rows.len());
+        let resize_task = Self::register_resize(&props, &link);
+        let mut table = Self {
+            link,
+            props,
+            sort,
+            search: String::new(),
+            filters: vec![],
+            range_inputs: HashMap::new(),
+            options: HashMap::new(),
+            page: 0,
+            order: vec![],
+            tree_rows: vec![],
+            expanded,
+            editing: None,
+            edited: None,
+            dirty,
+            edit_focus: None,
+            edit_ref: NodeRef::default(),
+            edit_listener: None,
+            layout,
+            resizing: None,
+            resize_listeners: vec![],
+            dragging: None,
+            head_ref: NodeRef::default(),
+            head_widths: vec![],
+            keys: vec![],
+            selected,
+            anchor: None,
+            shift: false,
+            viewport,
+            window: Window::default(),
+            container_ref: NodeRef::default(),
+            body_ref: NodeRef::default(),
+            restore_scroll: false,
+            resize_task,
+        };
+        table.update_rows();
+        table
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            DataTableMsg::Sort { column, multi } => {
+                self.sort = sort::toggle_sort(&self.sort, &column, multi);
+                self.props.onsort.emit(self.sort.clone());
+                self.update_order();
+                self.props.onquery.emit(self.query());
+                true
+            }
+            DataTableMsg::ToggleRow(position) => {
+                let shift = std::mem::take(&mut self.shift);
+                let key = match self.order.get(position).and_then(|idx| self.keys.get(*idx)) {
+                    Some(key) => key.clone(),
+                    None => return false,
+                };
+                let select = !self.selected.contains(&key);
+                let range = match self.anchor {
+                    Some(anchor) if shift => anchor.min(position)..=anchor.max(position),
+                    _ => position..=position,
+                };
+                for idx in self.order[range].iter() {
+                    let key = self.keys[*idx].clone();
+                    if select {
+                        self.selected.insert(key);
+                    } else {
+                        self.selected.remove(&key);
+                    }
+                }
+                self.anchor = Some(position);
+                self.emit_selection();
+                true
+            }
+            DataTableMsg::ToggleAll => {
+                let all_selected = self.order.iter().all(|idx| self.selected.contains(&self.keys[*idx]));
+                for idx in self.order.iter() {
+                    let key = self.keys[*idx].clone();
+                    if all_selected {
+                        self.selected.remove(&key);
+                    } else {
+                        self.selected.insert(key);
+                    }
+                }
+                self.anchor = None;
+                self.emit_selection();
+                true
+            }
+            DataTableMsg::Shift(shift) => {
+                self.shift = shift;
+                false
+            }
+            DataTableMsg::Scroll => {
+                if let Some(container) = self.container_ref.cast::<Element>() {
+                    self.viewport.scroll(&container);
+                }
+                self.window.neq_assign(self.viewport.window(self.props.overscan))
+            }
+            DataTableMsg::Search(search) => {
+                self.search = search;
+                self.update_query();
+                true
+            }
+            DataTableMsg::Filter { column, value } => {
+                self.set_filter(column, value);
+                true
+            }
+            DataTableMsg::FilterRange { column, upper, text } => {
+                let inputs = self.range_inputs.entry(column.clone()).or_default();
+                if upper {
+                    inputs.1 = text;
+                } else {
+                    inputs.0 = text;
+                }
+                // Bounds which are not valid numbers are ignored, rather than matching no rows.
+                let value = FilterValue::Range {
+                    min: inputs.0.trim().parse().ok(),
+                    max: inputs.1.trim().parse().ok(),
+                };
+                self.set_filter(column, value);
+                true
+            }
+            DataTableMsg::Page(page) => {
+                if page == self.page {
+                    return false;
+                }
+                self.page = page;
+                self.update_page();
+                self.props.onquery.emit(self.query());
+                true
+            }
+            DataTableMsg::Expand { position, expanded } => {
+                let key = match self.order.get(position).and_then(|idx| self.keys.get(*idx)) {
+                    Some(key) => key.clone(),
+                    None => return false,
+                };
+                if expanded {
+                    self.expanded.insert(key.clone());
+                    self.props.onexpand.emit(key);
+                } else {
+                    self.expanded.remove(&key);
+                    self.props.oncollapse.emit(key);
+                }
+                // Only the displayed rows of a tree table change as rows are expanded.
+                if self.props.tree.is_some() {
+                    self.update_order();
+                    self.update_viewport();
+                }
+                true
+            }
+            DataTableMsg::Edit { position, column } => {
+                let idx = match self.order.get(position) {
+                    Some(idx) if self.props.row_key.is_some() => *idx,
+                    _ => return false,
+                };
+                let value = self
+                    .props
+                    .columns
+                    .iter()
+                    .find(|col| col.id == column)
+                    .and_then(|col| col.render_value(&self.props.rows[idx]))
+                    .unwrap_or_default();
+                self.editing = Some(Editing {
+                    key: self.keys[idx].clone(),
+                    column,
+                    value,
+                    error: None,
+                });
+                self.edit_focus = Some(EditFocus::Editor);
+                self.edit_listener = None;
+                true
+            }
+            DataTableMsg::EditValue(value) => match &mut self.editing {
+                Some(editing) => {
+                    editing.value = value;
+                    true
+                }
+                None => false,
+            },
+            DataTableMsg::CommitEdit => {
+                let mut editing = match self.editing.take() {
+                    Some(editing) => editing,
+                    None => return false,
+                };
+                let column = match self.props.columns.iter().find(|col| col.id == editing.column) {
+                    Some(column) => column,
+                    None => return true,
+                };
+                if let Err(error) = column.validate_value(&editing.value) {
+                    editing.error = Some(error);
+                    self.editing = Some(editing);
+                    return true;
+                }
+                let current = self
+                    .keys
+                    .iter()
+                    .position(|key| *key == editing.key)
+                    .and_then(|idx| column.render_value(&self.props.rows[idx]));
+                if current.as_ref() != Some(&editing.value) {
+                    self.dirty.insert((editing.key.clone(), editing.column.clone()));
+                    self.props.onedit.emit(CellEdit {
+                        key: editing.key.clone(),
+                        column: editing.column.clone(),
+                        value: editing.value,
+                    });
+                }
+                self.edited = Some((editing.key, editing.column));
+                self.edit_focus = Some(EditFocus::Cell);
+                true
+            }
+            DataTableMsg::CancelEdit => match self.editing.take() {
+                Some(editing) => {
+                    self.edited = Some((editing.key, editing.column));
+                    self.edit_focus = Some(EditFocus::Cell);
+                    true
+                }
+                None => false,
+            },
+            DataTableMsg::ResizeStart { column, x, width } => {
+                let document = match web_sys::window().and_then(|window| window.document()) {
+                    Some(document) => document,
+                    None => return false,
+                };
+                let onmove = self.link.callback(DataTableMsg::ResizeMove);
+                let onend = self.link.callback(|_| DataTableMsg::ResizeEnd);
+                self.resize_listeners = vec![
+                    EventListener::new(&document, "mousemove", move |event| {
+                        if let Some(event) = event.dyn_ref::<MouseEvent>() {
+                            onmove.emit(f64::from(event.client_x()));
+                        }
+                    }),
+                    EventListener::new(&document, "mouseup", move |_| onend.emit(())),
+                ];
+                self.resizing = Some((column, x, width));
+                false
+            }
+            DataTableMsg::ResizeMove(x) => match &self.resizing {
+                Some((column, start, width)) => {
+                    self.layout
+                        .widths
+                        .insert(column.clone(), (width + x - start).max(MIN_COLUMN_WIDTH));
+                    true
+                }
+                None => false,
+            },
+            DataTableMsg::ResizeEnd => {
+                self.resize_listeners.clear();
+                if self.resizing.take().is_some() {
+                    self.props.onlayout.emit(self.layout.clone());
+                }
+                false
+            }
+            DataTableMsg::Resize { column, width } => {
+                self.layout.widths.insert(column, width.max(MIN_COLUMN_WIDTH));
+                self.props.onlayout.emit(self.layout.clone());
+                true
+            }
+            DataTableMsg::DragStart(column) => {
+                self.dragging = Some(column);
+                false
+            }
+            DataTableMsg::Drop(target) => {
+                let column = match self.dragging.take() {
+                    Some(column) => column,
+                    None => return false,
+                };
+                let ids = self.ordered_ids();
+                self.layout.move_column(ids, &column, &target);
+                self.props.onlayout.emit(self.layout.clone());
+                true
+            }
+            DataTableMsg::Export(format) => {
+                let content = export_rows(format, &self.visible_columns(), self.order.iter().map(|idx| &self.props.rows[*idx]));
+                let filename = format!("{}.{}", self.props.export_filename, format.extension());
+                // Downloads can only fail outside of a browser, where there is nothing to report to.
+                let _ = download_text(&filename, format.mime_type(), &content);
+                false
+            }
+            DataTableMsg::DragEnd => {
+                self.dragging = None;
+                false
+            }
+            DataTableMsg::SetHidden { column, hidden } => {
+                self.layout.set_hidden(&column, hidden);
+                self.props.onlayout.emit(self.layout.clone());
+                true
+            }
+            DataTableMsg::SetPinned { column, pin } => {
+                self.layout.set_pinned(&column, pin);
+                self.props.onlayout.emit(self.layout.clone());
+                true
+            }
+            DataTableMsg::Measure => {
+                let widths = match self.head_ref.cast::<Element>() {
+                    Some(head) => child_widths(&head),
+                    None => return false,
+                };
+                let changed = widths.len() != self.head_widths.len() || widths.iter().zip(&self.head_widths).any(|(a, b)| (a - b).abs() > 0.5);
+                self.head_widths = widths;
+                changed
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props.sort != self.props.sort {
+            self.sort = props.sort.clone();
+        }
+        if props.selection != self.props.selection {
+            self.selected = props.selection.iter().cloned().collect();
+        }
+        if props.expanded != self.props.expanded {
+            self.expanded = props.expanded.iter().cloned().collect();
+        }
+        if props.dirty != self.props.dirty {
+            self.dirty = props.dirty.iter().cloned().collect();
+        }
+        if props.layout != self.props.layout {
+            self.layout = props.layout.clone();
+        }
+        if props.virtualize.is_some() != self.props.virtualize.is_some() {
+            self.resize_task = Self::register_resize(&props, &self.link);
+        }
+        if self.props.neq_assign(props) {
+            self.update_rows();
+            self.restore_scroll = true;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if self.editing.is_none() {
+            self.edit_listener = None;
+        } else if self.edit_listener.is_none() {
+            if let Some(cell) = self.edit_ref.cast::<Element>() {
+                let commit = self.link.callback(|_| DataTableMsg::CommitEdit);
+                self.edit_listener = Some(EventListener::new(&cell, "focusout", move |_| commit.emit(())));
+            }
+        }
+        match self.edit_focus.take() {
+            Some(EditFocus::Editor) => {
+                let control = self
+                    .edit_ref
+                    .cast::<Element>()
+                    .and_then(|cell| cell.query_selector("input, select, textarea").ok().flatten())
+                    .and_then(|control| control.dyn_into::<HtmlElement>().ok());
+                if let Some(control) = control {
+                    let _ = control.focus();
+                }
+            }
+            Some(EditFocus::Cell) => {
+                if let Some(cell) = self.edit_ref.cast::<HtmlElement>() {
+                    let _ = cell.focus();
+                }
+            }
+            None => (),
+        }
+        // Pinned columns are positioned from the widths of the columns before them, and resizing
+        // via the keyboard starts from the current width of the column.
+        if self.props.customizable || !self.layout.pinned.is_empty() {
+            self.link.send_message(DataTableMsg::Measure);
+        }
+        if self.props.virtualize.is_none() {
+            return;
+        }
+        if self.restore_scroll {
+            self.restore_scroll = false;
+            if let Some(container) = self.container_ref.cast::<Element>() {
+                container.set_scroll_top(self.viewport.scroll_top as i32);
+            }
+        }
+        let measured = match self.body_ref.cast::<Element>() {
+            Some(body) => {
+                let heights = self.row_heights(&body);
+                self.viewport.measure(self.window.start, heights.into_iter())
+            }
+            None => false,
+        };
+        if first_render || measured {
+            self.link.send_message(DataTableMsg::Scroll);
+        }
+    }
+
+    fn view(&self) -> Html {
+        let classes = table_classes(
+            &self.props.classes,
+            TableStyle {
+                bordered: self.props.bordered,
+                striped: self.props.striped,
+                narrow: self.props.narrow,
+                hoverable: self.props.hoverable,
+                fullwidth: self.props.fullwidth,
+            },
+        );
+        let role = self.props.tree.as_ref().map(|_| "treegrid");
+        let table = html! {
+            <table class=classes role=role>
+                {self.view_head()}
+                {self.view_foot()}
+                {self.view_body()}
+            </table>
+        };
+        let table = if self.props.virtualize.is_some() {
+            html! {
+                <div
+                    ref=self.container_ref.clone()
+                    class="table-container"
+                    style=format!("max-height:{};overflow-y:auto;", self.props.viewport_height)
+                    onscroll=self.link.callback(|_| DataTableMsg::Scroll)
+                >
+                    {table}
+                </div>
+            }
+        } else if self.props.scrollable {
+            html! {<div class="table-container">{table}</div>}
+        } else {
+            table
+        };
+        let bulk_actions = if self.is_selectable() && !self.selected.is_empty() {
+            html! {<div class="block">{self.props.bulk_actions.clone()}</div>}
+        } else {
+            html! {}
+        };
+        html! {
+            <>
+            {self.view_search()}
+            {self.view_chooser()}
+            {self.view_exports()}
+            {bulk_actions}
+            {table}
+            {self.view_pagination()}
+            </>
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + 'static, K: Clone + Eq + Hash + 'static> DataTable<T, K> {
+    /// Register a window resize listener for re-measuring the scroll area, if virtualized.
+    fn register_resize(props: &DataTableProps<T, K>, link: &ComponentLink<Self>) -> Option<ResizeTask> {
+        props
+            .virtualize
+            .as_ref()
+            .map(|_| ResizeService::register(link.callback(|_| DataTableMsg::Scroll)))
+    }
+
+    /// The visible columns, in display order.
+    fn visible_columns(&self) -> Vec<&TableColumn<T>> {
+        self.layout
+            .arrange(self.props.columns.iter().map(|column| column.id.as_str()))
+            .into_iter()
+            .map(|idx| &self.props.columns[idx])
+            .collect()
+    }
+
+    /// The IDs of all columns, hidden or otherwise, in display order.
+    fn ordered_ids(&self) -> Vec<String> {
+        let layout = TableLayout {
+            order: self.layout.order.clone(),
+            ..TableLayout::default()
+        };
+        layout
+            .arrange(self.props.columns.iter().map(|column| column.id.as_str()))
+            .into_iter()
+            .map(|idx| self.props.columns[idx].id.clone())
+            .collect()
+    }
+
+    /// The sticky positioning of each header cell position, including that of the selection
+    /// column, as the side & offset in pixels of pinned columns.
+    fn sticky_offsets(&self, columns: &[&TableColumn<T>]) -> Vec<Option<(ColumnPin, f64)>> {
+        let selectable = self.is_selectable();
+        let pins = selectable
+            .then_some(None)
+            .into_iter()
+            .chain(columns.iter().map(|column| self.layout.pinned.get(&column.id).copied()))
+            .collect::<Vec<_>>();
+        // The selection column is pinned along with any columns pinned to the left.
+        let any_left = pins.contains(&Some(ColumnPin::Left));
+        let width = |pos: usize| {
+            let column = if selectable { pos.checked_sub(1) } else { Some(pos) };
+            column
+                .and_then(|col| self.layout.widths.get(&columns[col].id).copied())
+                .or_else(|| self.head_widths.get(pos).copied())
+                .unwrap_or_default()
+        };
+        let mut offsets = vec![None; pins.len()];
+        let mut left = 0.0;
+        for (pos, pin) in pins.iter().enumerate() {
+            if *pin == Some(ColumnPin::Left) || (selectable && pos == 0 && any_left) {
+                offsets[pos] = Some((ColumnPin::Left, left));
+                left += width(pos);
+            }
+        }
+        let mut right = 0.0;
+        for (pos, pin) in pins.iter().enumerate().rev() {
+            if *pin == Some(ColumnPin::Right) {
+                offsets[pos] = Some((ColumnPin::Right, right));
+                right += width(pos);
+            }
+        }
+        offsets
+    }
+
+    /// The effective query of this table.
+    fn query(&self) -> TableQuery {
+        TableQuery {
+            search: self.search.clone(),
+            filters: self.filters.clone(),
+            sort: self.sort.clone(),
+            page: self.page,
+            page_size: self.props.page_size,
+        }
+    }
+
+    /// Update the filter of the given column, removing it if it matches all rows.
+    fn set_filter(&mut self, column: String, value: FilterValue) {
+        let position = self.filters.iter().position(|(id, _)| *id == column);
+        match position {
+            Some(idx) if value.is_empty() => {
+                self.filters.remove(idx);
+            }
+            Some(idx) => self.filters[idx].1 = value,
+            None if value.is_empty() => {}
+            None => self.filters.push((column, value)),
+        }
+        self.update_query();
+    }
+
+    /// Return to the first page after a change of the search or the filters, recomputing the
+    /// matching rows & propagating the new query.
+    fn update_query(&mut self) {
+        self.page = 0;
+        self.update_order();
+        self.update_page();
+        self.props.onquery.emit(self.query());
+    }
+
+    /// The number of pages of rows, being at least one.
+    fn page_count(&self) -> usize {
+        match self.props.page_size {
+            Some(size) if size > 0 => {
+                let total = match self.props.total_rows {
+                    Some(total) if self.props.server => total,
+                    _ => self.order.len(),
+                };
+                total.div_ceil(size).max(1)
+            }
+            _ => 1,
+        }
+    }
+
+    /// The range of display positions of the rows of the current page.
+    fn page_range(&self) -> (usize, usize) {
+        match self.props.page_size {
+            Some(size) if size > 0 && !self.props.server => {
+                let start = (self.page * size).min(self.order.len());
+                (start, (start + size).min(self.order.len()))
+            }
+            _ => (0, self.order.len()),
+        }
+    }
+
+    /// Reset the scroll area of a virtualized table to the rows of the current page.
+    fn update_page(&mut self) {
+        if let Some(row_height) = &self.props.virtualize {
+            let (start, end) = self.page_range();
+            self.viewport.update(row_height.clone(), end - start);
+            self.viewport.scroll_top = 0.0;
+            self.window = self.viewport.window(self.props.overscan);
+            self.restore_scroll = true;
+        }
+    }
+
+    /// Measure the heights of the rendered rows of a virtualized table, including the heights of
+    /// their detail rows.
+    fn row_heights(&self, body: &Element) -> Vec<f64> {
+        let children = body.children();
+        let mut heights: Vec<f64> = vec![];
+        for child in (spacer_count(self.window.start) as u32..children.length()).filter_map(|idx| children.item(idx)) {
+            let height = child.get_bounding_client_rect().height();
+            if child.has_attribute("data-detail") {
+                if let Some(last) = heights.last_mut() {
+                    *last += height;
+                }
+            } else {
+                heights.push(height);
+            }
+        }
+        // Drop the height of the spacer row after the rendered rows.
+        heights.truncate(self.window.end - self.window.start);
+        heights
+    }
+
+    /// Check if the rows of this table can be expanded.
+    fn is_expandable(&self) -> bool {
+        (self.props.detail.is_some() || self.props.tree.is_some()) && self.props.row_key.is_some()
+    }
+
+    /// Check if the rows of this table can be selected.
+    fn is_selectable(&self) -> bool {
+        self.props.selectable && self.props.row_key.is_some()
+    }
+
+    /// Recompute the keys & display order of the rows, dropping selected keys which no longer
+    /// refer to a row.
+    fn update_rows(&mut self) {
+        self.keys = match &self.props.row_key {
+            Some(row_key) => self.props.rows.iter().map(|row| row_key.key(row)).collect(),
+            None => vec![],
+        };
+        let keys = self.keys.iter().collect::<HashSet<_>>();
+        self.selected.retain(|key| keys.contains(key));
+        self.anchor = None;
+        self.options = self
+            .props
+            .columns
+            .iter()
+            .filter(|column| column.filter_kind() == Some(&FilterKind::Select))
+            .map(|column| (column.id.clone(), filter::distinct_values(&self.props.rows, column)))
+            .collect();
+        self.update_order();
+        self.update_viewport();
+    }
+
+    /// Clamp the current page & update the scroll area of a virtualized table, after a change to
+    /// the number of displayed rows.
+    fn update_viewport(&mut self) {
+        // Remain on the last page when rows are removed from it.
+        if !self.props.server {
+            self.page = self.page.min(self.page_count() - 1);
+        }
+        if let Some(row_height) = &self.props.virtualize {
+            let (start, end) = self.page_range();
+            self.viewport.update(row_height.clone(), end - start);
+            self.window = self.viewport.window(self.props.overscan);
+        }
+    }
+
+    /// Propagate the keys of the selected rows, in display order.
+    fn emit_selection(&self) {
+        let selection = self
+            .order
+            .iter()
+            .map(|idx| &self.keys[*idx])
+            .filter(|key| self.selected.contains(*key))
+            .cloned()
+            .collect();
+        self.props.onselection.emit(selection);
+    }
+
+    /// Recompute the rows which match the filters & their display order.
+    fn update_order(&mut self) {
+        let mut order = (0..self.props.rows.len()).collect::<Vec<_>>();
+        let query = self.query();
+        let (rows, columns) = (&self.props.rows, &self.props.columns);
+        let matches = |idx: usize| self.props.server || query.matches(&rows[idx], columns);
+        match (&self.props.tree, self.props.row_key.is_some()) {
+            (Some(tree), true) => {
+                if !self.props.server {
+                    sort::sort_indices(&mut order, rows, columns, &self.sort);
+                }
+                let matches = (0..rows.len()).map(matches).collect::<Vec<_>>();
+                let (order, tree_rows) = tree::flatten(&order, &matches, rows, &self.keys, tree, &self.expanded);
+                self.order = order;
+                self.tree_rows = tree_rows;
+            }
+            _ => {
+                order.retain(|idx| matches(*idx));
+                if !self.props.server {
+                    sort::sort_indices(&mut order, rows, columns, &self.sort);
+                }
+                self.order = order;
+                self.tree_rows = vec![];
+            }
+        }
+        self.anchor = None;
+    }
+
+    fn view_head(&self) -> Html {
+        let columns = self.visible_columns();
+        let sticky = self.sticky_offsets(&columns);
+        let offset = self.is_selectable() as usize;
+        let cells = columns
+            .iter()
+            .enumerate()
+            .map(|(col, column)| {
+                let width = self.head_widths.get(col + offset).copied().unwrap_or_default();
+                self.view_header(column, sticky[col + offset], width)
+            })
+            .collect::<Html>();
+        let select = if self.is_selectable() {
+            let count = self
+                .order
+                .iter()
+                .filter(|idx| self.selected.contains(&self.keys[**idx]))
+                .count();
+            let all_selected = count > 0 && count == self.order.len();
+            let some_selected = count > 0 && count < self.order.len();
+            html! {
+                <th style=sticky_style(sticky[0], false)>
+                    <Checkbox
+                        name="select-all"
+                        checked=all_selected
+                        indeterminate=some_selected
+                        update=self.link.callback(|_| DataTableMsg::ToggleAll)
+                    />
+                </th>
+            }
+        } else {
+            html! {}
+        };
+        if self.props.virtualize.is_some() {
+            html! {
+                <thead style="position:sticky;top:0;z-index:2;background-color:inherit;">
+                    <tr ref=self.head_ref.clone()>{select}{cells}</tr>
+                    {self.view_filters(&columns, &sticky)}
+                </thead>
+            }
+        } else {
+            html! {
+                <thead>
+                    <tr ref=self.head_ref.clone()>{select}{cells}</tr>
+                    {self.view_filters(&columns, &sticky)}
+                </thead>
+            }
+        }
+    }
+
+    fn view_search(&self) -> Html {
+        if !self.props.searchable {
+            return html! {};
+        }
+        html! {
+            <div class="field">
+                <Control>
+                    <Input
+                        name="search"
+                        value=self.search.clone()
+                        update=self.link.callback(DataTableMsg::Search)
+                        placeholder=self.props.search_placeholder.clone()
+                    />
+                </Control>
+            </div>
+        }
+    }
+
+    /// Render the row of filter controls beneath the headers, if any column has a filter.
+    fn view_filters(&self, columns: &[&TableColumn<T>], sticky: &[Option<(ColumnPin, f64)>]) -> Html {
+        if columns.iter().all(|column| column.filter_kind().is_none()) {
+            return html! {};
+        }
+        let offset = self.is_selectable() as usize;
+        let cells = columns
+            .iter()
+            .enumerate()
+            .map(|(col, column)| html! {<th style=sticky_style(sticky[col + offset], false)>{self.view_filter(column)}</th>})
+            .collect::<Html>();
+        let select = if self.is_selectable() {
+            html! {<th style=sticky_style(sticky[0], false)></th>}
+        } else {
+            html! {}
+        };
+        html! {<tr>{select}{cells}</tr>}
+    }
+
+    /// Render the export buttons.
+    fn view_exports(&self) -> Html {
+        if self.props.exports.is_empty() {
+            return html! {};
+        }
+        let buttons = self
+            .props
+            .exports
+            .iter()
+            .map(|format| {
+                let format = *format;
+                html! {
+                    <Button classes=classes!("is-small") onclick=self.link.callback(move |_| DataTableMsg::Export(format))>
+                        {format!("Export {}", format.extension().to_uppercase())}
+                    </Button>
+                }
+            })
+            .collect::<Html>();
+        html! {<div class="buttons">{buttons}</div>}
+    }
+
+    /// Render the column chooser of a customizable table.
+    fn view_chooser(&self) -> Html {
+        if !self.props.customizable {
+            return html! {};
+        }
+        let items = self
+            .props
+            .columns
+            .iter()
+            .map(|column| {
+                let id = column.id.clone();
+                let hidden = self.layout.hidden.contains(&column.id);
+                let pin = self.layout.pinned.get(&column.id).copied();
+                let pin_button = |side: ColumnPin, title: &'static str, content: &'static str| {
+                    let id = column.id.clone();
+                    let active = pin == Some(side);
+                    let classes = if active {
+                        classes!("button", "is-small", "is-info", "is-selected")
+                    } else {
+                        classes!("button", "is-small")
+                    };
+                    let pin = if active { None } else { Some(side) };
+                    html! {
+                        <button
+                            class=classes
+                            type="button"
+                            title=title
+                            aria-pressed=active.to_string()
+                            onclick=self.link.callback(move |_| DataTableMsg::SetPinned { column: id.clone(), pin })
+                        >
+                            {content}
+                        </button>
+                    }
+                };
+                html! {
+                    <div class="dropdown-item">
+                        <div class="level is-mobile">
+                            <div class="level-left">
+                                <Checkbox
+                                    name=format!("column-{}", column.id)
+                                    checked=!hidden
+                                    update=self.link.callback(move |checked: bool| DataTableMsg::SetHidden { column: id.clone(), hidden: !checked })
+                                >
+                                    {column.header.clone()}
+                                </Checkbox>
+                            </div>
+                            <div class="level-right">
+                                <div class="buttons has-addons">
+                                    {pin_button(ColumnPin::Left, "Pin left", "\u{21e4}")}
+                                    {pin_button(ColumnPin::Right, "Pin right", "\u{21e5}")}
+                                </div>
+                            </div>
+                        </div>
+                    </div>
+                }
+            })
+            .collect::<Html>();
+        html! {
+            <div class="block">
+                <Dropdown button_html=self.props.columns_html.clone()>
+                    {items}
+                </Dropdown>
+            </div>
+        }
+    }
+
+    fn view_filter(&self, column: &TableColumn<T>) -> Html {
+        let current = self
+            .filters
+            .iter()
+            .find(|(id, _)| *id == column.id)
+            .map(|(_, value)| value);
+        let name = format!("filter-{}", column.id);
+        match column.filter_kind() {
+            Some(FilterKind::Text) => {
+                let value = match current {
+                    Some(FilterValue::Text(text)) => text.clone(),
+                    _ => String::new(),
+                };
+                let id = column.id.clone();
+                html! {
+                    <Input
+                        name=name
+                        value=value
+                        size=Size::Small
+                        update=self.link.callback(move |text| DataTableMsg::Filter { column: id.clone(), value: FilterValue::Text(text) })
+                    />
+                }
+            }
+            Some(FilterKind::Select) => {
+                let value = match current {
+                    Some(FilterValue::Select(selected)) => selected.clone(),
+                    _ => String::new(),
+                };
+                let options = self
+                    .options
+                    .get(&column.id)
+                    .map(|values| {
+                        values
+                            .iter()
+                            .map(|option| html! {<option value=option.clone() selected=*option == value>{option}</option>})
+                            .collect::<Html>()
+                    })
+                    .unwrap_or_default();
+                let id = column.id.clone();
+                html! {
+                    <Select
+                        name=name
+                        value=value.clone()
+                        size=Size::Small
+                        update=self.link.callback(move |selected| DataTableMsg::Filter { column: id.clone(), value: FilterValue::Select(selected) })
+                    >
+                        <option value="" selected=value.is_empty()>{"\u{2014}"}</option>
+                        {options}
+                    </Select>
+                }
+            }
+            Some(FilterKind::Range) => {
+                let (min, max) = self.range_inputs.get(&column.id).cloned().unwrap_or_default();
+                let (min_id, max_id) = (column.id.clone(), column.id.clone());
+                html! {
+                    <div class="field has-addons">
+                        <Control>
+                            <Input
+                                name=format!("{}-min", name)
+                                value=min
+                                size=Size::Small
+                                placeholder="min"
+                                update=self.link.callback(move |text| DataTableMsg::FilterRange { column: min_id.clone(), upper: false, text })
+                            />
+                        </Control>
+                        <Control>
+                            <Input
+                                name=format!("{}-max", name)
+                                value=max
+                                size=Size::Small
+                                placeholder="max"
+                                update=self.link.callback(move |text| DataTableMsg::FilterRange { column: max_id.clone(), upper: true, text })
+                            />
+                        </Control>
+                    </div>
+                }
+            }
+            None => html! {},
+        }
+    }
+
+    fn view_pagination(&self) -> Html {
+        if self.props.page_size.is_none() {
+            return html! {};
+        }
+        let (page, count) = (self.page, self.page_count());
+        let link = |idx: usize| {
+            html! {
+                <li>
+                    <PaginationItem
+                        item_type=PaginationItemType::Link
+                        label=format!("Goto page {}", idx + 1)
+                        current=idx == page
+                        onclick=self.link.callback(move |_| DataTableMsg::Page(idx))
+                    >
+                        {idx + 1}
+                    </PaginationItem>
+                </li>
+            }
+        };
+        let ellipsis = || html! {<li><PaginationEllipsis/></li>};
+        // The first & last pages are always linked, along with the pages adjacent to the current page.
+        let (start, end) = (page.saturating_sub(1).max(1), (page + 2).min(count - 1));
+        let mut items = vec![link(0)];
+        if start > 1 {
+            items.push(ellipsis());
+        }
+        items.extend((start..end).map(link));
+        if end < count - 1 {
+            items.push(ellipsis());
+        }
+        if count > 1 {
+            items.push(link(count - 1));
+        }
+        let (is_first, is_last) = (page == 0, page + 1 >= count);
+        let previous = html! {
+            <PaginationItem
+                item_type=PaginationItemType::Previous
+                disabled=is_first
+                onclick=self.link.batch_callback(move |_| if is_first { vec![] } else { vec![DataTableMsg::Page(page - 1)] })
+            >
+                {"Previous"}
+            </PaginationItem>
+        };
+        let next = html! {
+            <PaginationItem
+                item_type=PaginationItemType::Next
+                disabled=is_last
+                onclick=self.link.batch_callback(move |_| if is_last { vec![] } else { vec![DataTableMsg::Page(page + 1)] })
+            >
+                {"Next"}
+            </PaginationItem>
+        };
+        html! {
+            <Pagination previous=previous next=next>
+                {items}
+            </Pagination>
+        }
+    }
+
+    /// Render the header cell of the given column, with the given sticky positioning & measured
+    /// width.
+    fn view_header(&self, column: &TableColumn<T>, sticky: Option<(ColumnPin, f64)>, measured: f64) -> Html {
+        let mut style = match self.layout.widths.get(&column.id) {
+            Some(width) => format!("width:{0}px;min-width:{0}px;", width),
+            None => column
+                .width
+                .as_ref()
+                .map(|width| format!("width:{};", width))
+                .unwrap_or_default(),
+        };
+        match sticky {
+            Some(_) => style.push_str(&sticky_style(sticky, false)),
+            // The resize handle is positioned within the header cell.
+            None if self.props.customizable => style.push_str("position:relative;"),
+            None => (),
+        }
+        let content = if column.is_sortable() {
+            self.view_sort_button(column)
+        } else {
+            column.header.clone()
+        };
+        let aria_sort = if column.is_sortable() {
+            let direction = self
+                .sort
+                .iter()
+                .find(|key| key.column == column.id)
+                .map(|key| key.direction);
+            Some(direction.map(SortDirection::aria_sort).unwrap_or("none"))
+        } else {
+            None
+        };
+        if !self.props.customizable {
+            return html! {
+                <th class=classes!(column.alignment_class()) style=style aria-sort=aria_sort>
+                    {content}
+                </th>
+            };
+        }
+
+        let id = column.id.clone();
+        let ondragstart = self.link.callback(move |event: DragEvent| {
+            // Firefox only starts dragging once data is set.
+            if let Some(data) = event.data_transfer() {
+                let _ = data.set_data("text/plain", &id);
+            }
+            DataTableMsg::DragStart(id.clone())
+        });
+        let ondragover = Callback::from(|event: DragEvent| event.prevent_default());
+        let id = column.id.clone();
+        let ondrop = self.link.callback(move |event: DragEvent| {
+            event.prevent_default();
+            DataTableMsg::Drop(id.clone())
+        });
+        let id = column.id.clone();
+        let onmousedown = self.link.batch_callback(move |event: MouseEvent| {
+            // Keep the header from being dragged & the text from being selected.
+            event.prevent_default();
+            event.stop_propagation();
+            let header = event
+                .target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+                .and_then(|handle| handle.parent_element());
+            match header {
+                Some(header) => vec![DataTableMsg::ResizeStart {
+                    column: id.clone(),
+                    x: f64::from(event.client_x()),
+                    width: header.get_bounding_client_rect().width(),
+                }],
+                None => vec![],
+            }
+        });
+        let id = column.id.clone();
+        let width = self.layout.widths.get(&column.id).copied().unwrap_or(measured);
+        let onkeydown = self
+            .link
+            .batch_callback(move |event: KeyboardEvent| match event.key().as_str() {
+                "ArrowLeft" => vec![DataTableMsg::Resize { column: id.clone(), width: width - RESIZE_STEP }],
+                "ArrowRight" => vec![DataTableMsg::Resize { column: id.clone(), width: width + RESIZE_STEP }],
+                _ => vec![],
+            });
+        html! {
+            <th
+                class=classes!(column.alignment_class())
+                style=style
+                aria-sort=aria_sort
+                draggable="true"
+                ondragstart=ondragstart
+                ondragover=ondragover
+                ondrop=ondrop
+                ondragend=self.link.callback(|_| DataTableMsg::DragEnd)
+            >
+                {content}
+                <span
+                    role="separator"
+                    tabindex="0"
+                    aria-orientation="vertical"
+                    aria-label="Resize column"
+                    style="position:absolute;top:0;right:0;bottom:0;width:5px;cursor:col-resize;"
+                    onmousedown=onmousedown
+                    onkeydown=onkeydown
+                ></span>
+            </th>
+        }
+    }
+
+    /// Render the sort button of the header of the given sortable column.
+    fn view_sort_button(&self, column: &TableColumn<T>) -> Html {
+        let position = self.sort.iter().position(|key| key.column == column.id);
+        let indicator = match position.map(|idx| self.sort[idx].direction) {
+            Some(SortDirection::Ascending) => self.props.sort_asc_html.clone(),
+            Some(SortDirection::Descending) => self.props.sort_desc_html.clone(),
+            None => html! {},
+        };
+        // The priority of the column is only shown when sorting by multiple columns.
+        let priority = match position {
+            Some(idx) if self.sort.len() > 1 => html! {<sup>{idx + 1}</sup>},
+            _ => html! {},
+        };
+        let id = column.id.clone();
+        let onclick = self
+            .link
+            .callback(move |event: MouseEvent| DataTableMsg::Sort { column: id.clone(), multi: event.shift_key() });
+        let id = column.id.clone();
+        let onkeydown = self
+            .link
+            .batch_callback(move |event: KeyboardEvent| match event.key().as_str() {
+                "Enter" | " " => {
+                    event.prevent_default();
+                    vec![DataTableMsg::Sort { column: id.clone(), multi: event.shift_key() }]
+                }
+                _ => vec![],
+            });
+        html! {
+            <a role="button" tabindex="0" onclick=onclick onkeydown=onkeydown>
+                {column.header.clone()}
+                <Icon size=Size::Small>{indicator}{priority}</Icon>
+            </a>
+        }
+    }
+
+    fn view_foot(&self) -> Html {
+        let columns = self.visible_columns();
+        if columns.iter().all(|column| !column.has_footer()) {
+            return html! {};
+        }
+        let sticky = self.sticky_offsets(&columns);
+        let offset = self.is_selectable() as usize;
+        let filtered;
+        let rows = if self.props.server || self.order.len() == self.props.rows.len() {
+            &self.props.rows
+        } else {
+            filtered = self
+                .order
+                .iter()
+                .map(|idx| self.props.rows[*idx].clone())
+                .collect::<Vec<_>>();
+            &filtered
+        };
+        let cells = columns
+            .iter()
+            .enumerate()
+            .map(|(col, column)| {
+                html! {
+                    <th class=classes!(column.alignment_class()) style=sticky_style(sticky[col + offset], false)>
+                        {column.render_footer(rows).unwrap_or_default()}
+                    </th>
+                }
+            })
+            .collect::<Html>();
+        let select = if self.is_selectable() {
+            html! {<th style=sticky_style(sticky[0], false)></th>}
+        } else {
+            html! {}
+        };
+        html! {<tfoot><tr>{select}{cells}</tr></tfoot>}
+    }
+
+    /// Render the expand/collapse toggle of the row at the given display position; leaf rows of a
+    /// tree table get an empty placeholder, keeping their content aligned with their siblings'.
+    fn view_toggle(&self, position: usize, tree_row: &tree::TreeRow, is_expanded: bool) -> Html {
+        if self.props.detail.is_none() && !tree_row.branch {
+            return html! {<Icon size=Size::Small/>};
+        }
+        let (content, label) = if is_expanded {
+            (self.props.collapse_html.clone(), "Collapse row")
+        } else {
+            (self.props.expand_html.clone(), "Expand row")
+        };
+        let onclick = self
+            .link
+            .callback(move |_| DataTableMsg::Expand { position, expanded: !is_expanded });
+        let onkeydown = self
+            .link
+            .batch_callback(move |event: KeyboardEvent| match event.key().as_str() {
+                "Enter" | " " => {
+                    // Keep the cell from being edited, as the toggle is within the first cell.
+                    event.prevent_default();
+                    event.stop_propagation();
+                    vec![DataTableMsg::Expand { position, expanded: !is_expanded }]
+                }
+                "ArrowRight" if !is_expanded => vec![DataTableMsg::Expand { position, expanded: true }],
+                "ArrowLeft" if is_expanded => vec![DataTableMsg::Expand { position, expanded: false }],
+                _ => vec![],
+            });
+        html! {
+            <a
+                role="button"
+                tabindex="0"
+                aria-label=label
+                aria-expanded=is_expanded.to_string()
+                onclick=onclick
+                onkeydown=onkeydown
+            >
+                <Icon size=Size::Small>{content}</Icon>
+            </a>
+        }
+    }
+
+    /// Render the cell of the given column of the row with the given index, with the given
+    /// content before the cell's content.
+    fn view_cell(&self, idx: usize, position: usize, column: &TableColumn<T>, prefix: Html, style: String) -> Html {
+        let row = &self.props.rows[idx];
+        let mut classes = classes!(column.alignment_class());
+        let editor = match column.editor() {
+            Some(editor) if self.props.row_key.is_some() => editor,
+            _ => return html! {<td class=classes style=style>{prefix}{column.render_cell(row)}</td>},
+        };
+        let key = &self.keys[idx];
+        let is_cell = |(cell_key, cell_column): (&K, &String)| cell_key == key && *cell_column == column.id;
+        if self.dirty.contains(&(key.clone(), column.id.clone())) {
+            classes.push("has-background-warning-light");
+        }
+        if let Some(editing) = self
+            .editing
+            .as_ref()
+            .filter(|editing| is_cell((&editing.key, &editing.column)))
+        {
+            return html! {
+                <td ref=self.edit_ref.clone() class=classes style=style>
+                    {prefix}
+                    {self.view_editor(editor, editing)}
+                </td>
+            };
+        }
+        let cell_ref = match &self.edited {
+            Some((key, column)) if self.editing.is_none() && is_cell((key, column)) => self.edit_ref.clone(),
+            _ => NodeRef::default(),
+        };
+        let id = column.id.clone();
+        let ondblclick = self
+            .link
+            .callback(move |_| DataTableMsg::Edit { position, column: id.clone() });
+        let id = column.id.clone();
+        let onkeydown = self
+            .link
+            .batch_callback(move |event: KeyboardEvent| match event.key().as_str() {
+                "Enter" => {
+                    event.prevent_default();
+                    vec![DataTableMsg::Edit { position, column: id.clone() }]
+                }
+                _ => vec![],
+            });
+        html! {
+            <td ref=cell_ref class=classes style=style tabindex="0" ondblclick=ondblclick onkeydown=onkeydown>
+                {prefix}
+                {column.render_cell(row)}
+            </td>
+        }
+    }
+
+    /// Render the form control of the cell being edited, along with its validation error.
+    fn view_editor(&self, editor: &CellEditor, editing: &Editing<K>) -> Html {
+        let multiline = *editor == CellEditor::TextArea;
+        let onkeydown = self
+            .link
+            .batch_callback(move |event: KeyboardEvent| match event.key().as_str() {
+                "Enter" if !(multiline && event.shift_key()) => {
+                    event.prevent_default();
+                    event.stop_propagation();
+                    vec![DataTableMsg::CommitEdit]
+                }
+                "Escape" => {
+                    event.stop_propagation();
+                    vec![DataTableMsg::CancelEdit]
+                }
+                _ => vec![],
+            });
+        let classes = editing.error.as_ref().map(|_| Classes::from("is-danger"));
+        let name = format!("edit-{}", editing.column);
+        let update = self.link.callback(DataTableMsg::EditValue);
+        let control = match editor {
+            CellEditor::Input => html! {
+                <Input name=name value=editing.value.clone() update=update classes=classes size=Size::Small/>
+            },
+            CellEditor::TextArea => html! {
+                <TextArea name=name value=editing.value.clone() update=update classes=classes size=Size::Small/>
+            },
+            CellEditor::Select(options) => {
+                let options = options
+                    .iter()
+                    .map(|(value, label)| html! {<option value=value.clone() selected=*value == editing.value>{label}</option>})
+                    .collect::<Html>();
+                html! {
+                    <Select name=name value=editing.value.clone() update=update classes=classes size=Size::Small>
+                        {options}
+                    </Select>
+                }
+            }
+            CellEditor::Checkbox => html! {
+                <Checkbox
+                    name=name
+                    checked=editing.value == "true"
+                    update=self.link.callback(|checked: bool| DataTableMsg::EditValue(checked.to_string()))
+                />
+            },
+        };
+        let error = match &editing.error {
+            Some(error) => html! {<p class="help is-danger">{error}</p>},
+            None => html! {},
+        };
+        html! {
+            <div onkeydown=onkeydown>
+                {control}
+                {error}
+            </div>
+        }
+    }
+
+    fn view_body(&self) -> Html {
+        let selectable = self.is_selectable();
+        if self.order.is_empty() {
+            let colspan = (self.visible_columns().len() + selectable as usize).to_string();
+            return html! {
+                <tbody>
+                    <tr><td colspan=colspan>{self.props.empty.clone()}</td></tr>
+                </tbody>
+            };
+        }
+        let (page_start, page_end) = self.page_range();
+        let (start, end) = match self.props.virtualize {
+            Some(_) => (page_start + self.window.start, page_start + self.window.end),
+            None => (page_start, page_end),
+        };
+        let columns = self.visible_columns();
+        let sticky = self.sticky_offsets(&columns);
+        let offset = selectable as usize;
+        let colspan = (columns.len() + offset).to_string();
+        let rows = self.order[start..end]
+            .iter()
+            .zip(start..)
+            .map(|(idx, position)| {
+                let row = &self.props.rows[*idx];
+                let tree_row = self.tree_rows.get(position).cloned().unwrap_or_default();
+                let is_expanded = self.is_expandable() && self.expanded.contains(&self.keys[*idx]);
+                let is_selected = selectable && self.selected.contains(&self.keys[*idx]);
+                // Selected rows & the even rows of striped tables have a background of their own.
+                let opaque_row = is_selected || (self.props.striped && (position - page_start) % 2 == 1);
+                let cells = columns
+                    .iter()
+                    .enumerate()
+                    .map(|(col, column)| {
+                        let style = sticky_style(sticky[col + offset], opaque_row);
+                        if col > 0 || !self.is_expandable() {
+                            return self.view_cell(*idx, position, column, html! {}, style);
+                        }
+                        let style = format!("{}padding-left:{}em;", style, 0.75 + 1.5 * tree_row.depth as f64);
+                        let toggle = self.view_toggle(position, &tree_row, is_expanded);
+                        self.view_cell(*idx, position, column, toggle, style)
+                    })
+                    .collect::<Html>();
+                let select = if selectable {
+                    html! {
+                        <td style=sticky_style(sticky[0], opaque_row) onmousedown=self.link.callback(|event: MouseEvent| DataTableMsg::Shift(event.shift_key()))>
+                            <Checkbox
+                                name="select"
+                                checked=is_selected
+                                update=self.link.callback(move |_| DataTableMsg::ToggleRow(position))
+                            />
+                        </td>
+                    }
+                } else {
+                    html! {}
+                };
+                let mut classes = Classes::new();
+                if is_selected {
+                    classes.push("is-selected");
+                }
+                let (aria_level, aria_expanded) = match &self.props.tree {
+                    Some(_) if self.is_expandable() => {
+                        let aria_expanded = if tree_row.branch { Some(is_expanded.to_string()) } else { None };
+                        (Some((tree_row.depth + 1).to_string()), aria_expanded)
+                    }
+                    _ => (None, None),
+                };
+                let detail = match &self.props.detail {
+                    Some(detail) if is_expanded => html! {
+                        <tr data-detail="true"><td colspan=colspan.clone()>{detail.render(row)}</td></tr>
+                    },
+                    _ => html! {},
+                };
+                let loading = if tree_row.loading {
+                    html! {<tr data-detail="true"><td colspan=colspan.clone()>{self.props.loading_html.clone()}</td></tr>}
+                } else {
+                    html! {}
+                };
+                html! {
+                    <>
+                    <tr class=classes aria-level=aria_level aria-expanded=aria_expanded>
+                        {select}
+                        {cells}
+                    </tr>
+                    {detail}
+                    {loading}
+                    </>
+                }
+            })
+            .collect::<Html>();
+        if self.props.virtualize.is_none() {
+            return html! {<tbody>{rows}</tbody>};
+        }
+        let spacer = |height: f64| html! {<tr aria-hidden="true" style=format!("height:{}px;", height)></tr>};
+        let before = match spacer_count(self.window.start) {
+            0 => html! {},
+            1 => spacer(self.window.before),
+            _ => html! {<>{spacer(self.window.before)}{spacer(0.0)}</>},
+        };
+        html! {
+            <tbody ref=self.body_ref.clone()>
+                {before}
+                {rows}
+                {spacer(self.window.after)}
+            </tbody>
+        }
+    }
+}
+
+/// The inline style of a cell with the given sticky positioning.
+///
+/// The cells of pinned columns are given an opaque background, so that they cover the cells which
+/// scroll beneath them; inheriting that of their row when the row has a background of its own.
+fn sticky_style(sticky: Option<(ColumnPin, f64)>, opaque_row: bool) -> String {
+    let (side, offset) = match sticky {
+        Some((ColumnPin::Left, offset)) => ("left", offset),
+        Some((ColumnPin::Right, offset)) => ("right", offset),
+        None => return String::new(),
+    };
+    let background = if opaque_row { "inherit" } else { "white" };
+    format!("position:sticky;{}:{}px;z-index:1;background-color:{};", side, offset, background)
+}
+
+/// Measure the widths of the child elements of the given element.
+fn child_widths(parent: &Element) -> Vec<f64> {
+    let children = parent.children();
+    (0..children.length())
+        .filter_map(|idx| children.item(idx))
+        .map(|child| child.get_bounding_client_rect().width())
+        .collect()
+}
+
+/// The number of spacer rows rendered before the rows of a virtualized table.
+///
+/// This keeps the parity of the rendered rows equal to that of their display positions, so that
+/// the stripes of a striped table do not alternate as the table is scrolled.
+fn spacer_count(start: usize) -> usize {
+    match start {
+        0 => 0,
+        start if start % 2 == 1 => 1,
+        _ => 2,
+    }
+}
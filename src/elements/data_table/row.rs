@@ -0,0 +1,39 @@
+use std::fmt;
+use std::rc::Rc;
+
+/// A function extracting the key of a row of a `DataTable`.
+///
+/// Row keys identify rows across changes to the rows of a table, such as for tracking which rows
+/// are selected, and are the values through which rows are reported to the parent component.
+pub struct RowKey<T, K>(Rc<dyn Fn(&T) -> K>);
+
+impl<T, K> RowKey<T, K> {
+    /// Create a new row key from the given key extraction function.
+    pub fn new(key: impl Fn(&T) -> K + 'static) -> Self {
+        Self(Rc::new(key))
+    }
+
+    /// Extract the key of the given row.
+    pub fn key(&self, row: &T) -> K {
+        (self.0)(row)
+    }
+}
+
+impl<T, K> Clone for RowKey<T, K> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// Row keys are equal when they share the same key extraction function.
+impl<T, K> PartialEq for RowKey<T, K> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T, K> fmt::Debug for RowKey<T, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RowKey").finish()
+    }
+}
@@ -0,0 +1,89 @@
+use std::cmp::Ordering;
+
+use super::TableColumn;
+
+/// The direction in which a `DataTable` column is sorted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    /// The value of the `aria-sort` attribute for this direction.
+    pub(crate) fn aria_sort(self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "ascending",
+            SortDirection::Descending => "descending",
+        }
+    }
+}
+
+/// A column of a `DataTable` by which rows are sorted, along with the sort direction.
+///
+/// The sort state of a table is a list of these keys in order of priority; rows which are equal
+/// according to the first key are ordered by the second key, and so on.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SortKey {
+    /// The ID of the column.
+    pub column: String,
+    /// The direction in which the column is sorted.
+    pub direction: SortDirection,
+}
+
+/// Cycle the sort direction of a column through ascending, descending & unsorted.
+///
+/// When `multi` is `true`, the other sort keys are retained & a newly sorted column is given the
+/// lowest priority; otherwise the column becomes the only sort key.
+pub(crate) fn toggle_sort(state: &[SortKey], column: &str, multi: bool) -> Vec<SortKey> {
+    let next = match state.iter().find(|key| key.column == column).map(|key| key.direction) {
+        None => Some(SortDirection::Ascending),
+        Some(SortDirection::Ascending) => Some(SortDirection::Descending),
+        Some(SortDirection::Descending) => None,
+    };
+    if !multi {
+        return next
+            .map(|direction| SortKey { column: column.into(), direction })
+            .into_iter()
+            .collect();
+    }
+    let mut state = state.to_vec();
+    match (state.iter().position(|key| key.column == column), next) {
+        (Some(idx), Some(direction)) => state[idx].direction = direction,
+        (Some(idx), None) => {
+            state.remove(idx);
+        }
+        (None, Some(direction)) => state.push(SortKey { column: column.into(), direction }),
+        (None, None) => (),
+    }
+    state
+}
+
+/// Sort the given row indices according to the sort state, using the comparators of the columns.
+///
+/// Keys referring to unknown or unsortable columns are ignored. The sort is stable, so rows which
+/// are equal according to all keys retain their relative order.
+pub(crate) fn sort_indices<T>(indices: &mut [usize], rows: &[T], columns: &[TableColumn<T>], state: &[SortKey]) {
+    let keys = state
+        .iter()
+        .filter_map(|key| {
+            let column = columns.iter().find(|column| column.id == key.column)?;
+            column.comparator().map(|compare| (compare, key.direction))
+        })
+        .collect::<Vec<_>>();
+    if keys.is_empty() {
+        return;
+    }
+    indices.sort_by(|a, b| {
+        keys.iter()
+            .map(|(compare, direction)| {
+                let ordering = compare(&rows[*a], &rows[*b]);
+                match direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            })
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    });
+}
@@ -0,0 +1,153 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// The hierarchy of the rows of a tree table, given by the key of the parent of each row.
+///
+/// Rows whose parent key is `None`, or does not refer to another row, are displayed at the top
+/// level. Child rows are displayed beneath their parent row while it is expanded, indented by
+/// their depth & sorted among their siblings.
+pub struct RowTree<T, K> {
+    parent: ParentFn<T, K>,
+    lazy: Option<LazyFn<T>>,
+}
+
+type ParentFn<T, K> = Rc<dyn Fn(&T) -> Option<K>>;
+type LazyFn<T> = Rc<dyn Fn(&T) -> bool>;
+
+impl<T, K> RowTree<T, K> {
+    /// Create a new row hierarchy from the given function extracting the key of a row's parent.
+    pub fn new(parent: impl Fn(&T) -> Option<K> + 'static) -> Self {
+        Self { parent: Rc::new(parent), lazy: None }
+    }
+
+    /// Load the children of rows lazily, given a function checking if a row has children.
+    ///
+    /// Rows which have children but none of whose children are among the rows of the table can
+    /// still be expanded, which propagates the row's key via the table's `onexpand` callback so
+    /// that its children can be loaded. A loading indicator is displayed until they are.
+    pub fn lazy(mut self, has_children: impl Fn(&T) -> bool + 'static) -> Self {
+        self.lazy = Some(Rc::new(has_children));
+        self
+    }
+
+    /// Extract the key of the parent of the given row.
+    pub fn parent(&self, row: &T) -> Option<K> {
+        (self.parent)(row)
+    }
+
+    /// Check if the given row has children which are loaded lazily.
+    fn has_lazy_children(&self, row: &T) -> bool {
+        self.lazy.as_ref().map(|lazy| lazy(row)).unwrap_or(false)
+    }
+}
+
+impl<T, K> Clone for RowTree<T, K> {
+    fn clone(&self) -> Self {
+        Self {
+            parent: self.parent.clone(),
+            lazy: self.lazy.clone(),
+        }
+    }
+}
+
+/// Row hierarchies are equal when they share the same functions.
+impl<T, K> PartialEq for RowTree<T, K> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.parent, &other.parent)
+            && match (&self.lazy, &other.lazy) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+impl<T, K> fmt::Debug for RowTree<T, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RowTree").field("lazy", &self.lazy.is_some()).finish()
+    }
+}
+
+/// The position of a displayed row within the hierarchy of a tree table.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct TreeRow {
+    /// The number of ancestors of the row.
+    pub depth: usize,
+    /// Whether the row has children, loaded or otherwise, and can thus be expanded.
+    pub branch: bool,
+    /// Whether the row is expanded while its children are yet to be loaded.
+    pub loading: bool,
+}
+
+/// Compute the displayed rows of a tree table, in display order, along with their positions in
+/// the hierarchy.
+///
+/// `sorted` holds the indices of all rows in sorted order, and `matches` whether each row matches
+/// the table's filters. Rows are displayed if they match or any of their descendants match, and
+/// all of their ancestors are expanded.
+pub(crate) fn flatten<T, K: Eq + Hash>(
+    sorted: &[usize], matches: &[bool], rows: &[T], keys: &[K], tree: &RowTree<T, K>, expanded: &HashSet<K>,
+) -> (Vec<usize>, Vec<TreeRow>) {
+    let index = keys
+        .iter()
+        .enumerate()
+        .map(|(idx, key)| (key, idx))
+        .collect::<HashMap<_, _>>();
+    let parents = rows
+        .iter()
+        .enumerate()
+        .map(|(idx, row)| {
+            tree.parent(row)
+                .and_then(|key| index.get(&key).copied())
+                .filter(|parent| *parent != idx)
+        })
+        .collect::<Vec<_>>();
+
+    // Include the ancestors of matching rows; stopping at included rows also guards against cycles.
+    let mut included = matches.to_vec();
+    for idx in (0..rows.len()).filter(|idx| matches[*idx]) {
+        let mut parent = parents[idx];
+        while let Some(idx) = parent {
+            if included[idx] {
+                break;
+            }
+            included[idx] = true;
+            parent = parents[idx];
+        }
+    }
+
+    // Rows with children which do not match the filters are not loaded lazily.
+    let mut loaded = vec![false; rows.len()];
+    for parent in parents.iter().flatten() {
+        loaded[*parent] = true;
+    }
+
+    let mut roots = vec![];
+    let mut children = vec![vec![]; rows.len()];
+    for idx in sorted.iter().copied().filter(|idx| included[*idx]) {
+        match parents[idx] {
+            Some(parent) => children[parent].push(idx),
+            None => roots.push(idx),
+        }
+    }
+
+    let mut order = vec![];
+    let mut tree_rows = vec![];
+    let mut stack = roots.into_iter().rev().map(|idx| (idx, 0)).collect::<Vec<_>>();
+    while let Some((idx, depth)) = stack.pop() {
+        let is_expanded = expanded.contains(&keys[idx]);
+        let lazy = !loaded[idx] && tree.has_lazy_children(&rows[idx]);
+        order.push(idx);
+        tree_rows.push(TreeRow {
+            depth,
+            branch: !children[idx].is_empty() || lazy,
+            loading: is_expanded && lazy,
+        });
+        if is_expanded {
+            stack.extend(children[idx].iter().rev().map(|child| (*child, depth + 1)));
+        }
+    }
+    (order, tree_rows)
+}
@@ -2,6 +2,7 @@ pub mod block;
 pub mod r#box;
 pub mod button;
 pub mod content;
+pub mod data_table;
 pub mod delete;
 pub mod icon;
 pub mod image;
@@ -51,23 +51,16 @@ impl Component for Table {
     }
 
     fn view(&self) -> Html {
-        let mut classes = Classes::from("table");
-        classes.push(&self.props.classes);
-        if self.props.bordered {
-            classes.push("is-bordered");
-        }
-        if self.props.striped {
-            classes.push("is-striped");
-        }
-        if self.props.narrow {
-            classes.push("is-narrow");
-        }
-        if self.props.hoverable {
-            classes.push("is-hoverable");
-        }
-        if self.props.fullwidth {
-            classes.push("is-fullwidth");
-        }
+        let classes = table_classes(
+            &self.props.classes,
+            TableStyle {
+                bordered: self.props.bordered,
+                striped: self.props.striped,
+                narrow: self.props.narrow,
+                hoverable: self.props.hoverable,
+                fullwidth: self.props.fullwidth,
+            },
+        );
         if self.props.scrollable {
             html! {
                 <div class="table-container">
@@ -85,3 +78,34 @@ impl Component for Table {
         }
     }
 }
+
+/// The boolean style modifiers shared by the table components.
+pub(crate) struct TableStyle {
+    pub bordered: bool,
+    pub striped: bool,
+    pub narrow: bool,
+    pub hoverable: bool,
+    pub fullwidth: bool,
+}
+
+/// Build the classes of the `table` element.
+pub(crate) fn table_classes(extra: &Option<Classes>, style: TableStyle) -> Classes {
+    let mut classes = Classes::from("table");
+    classes.push(extra);
+    if style.bordered {
+        classes.push("is-bordered");
+    }
+    if style.striped {
+        classes.push("is-striped");
+    }
+    if style.narrow {
+        classes.push("is-narrow");
+    }
+    if style.hoverable {
+        classes.push("is-hoverable");
+    }
+    if style.fullwidth {
+        classes.push("is-fullwidth");
+    }
+    classes
+}
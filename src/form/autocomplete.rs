@@ -0,0 +1,416 @@
+#![allow(clippy::unnecessary_operation)]
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::time::Duration;
+
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+use yew::services::timeout::{TimeoutService, TimeoutTask};
+use yewtil::NeqAssign;
+
+use crate::{Control, Input, Size};
+
+/// The future of a request for the suggestions of an `Autocomplete`, resolving to the suggestions.
+pub type SuggestionsFuture<T> = Pin<Box<dyn Future<Output = Vec<T>>>>;
+
+type SuggestFn<T> = Rc<dyn Fn(String) -> SuggestionsFuture<T>>;
+
+/// The source of the suggestions of an `Autocomplete`, given the text of its input.
+pub struct Suggestions<T>(SuggestFn<T>);
+
+impl<T> Suggestions<T> {
+    /// Create a new source of suggestions from the given function.
+    pub fn new(suggest: impl Fn(String) -> SuggestionsFuture<T> + 'static) -> Self {
+        Self(Rc::new(suggest))
+    }
+}
+
+impl<T> Clone for Suggestions<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// Sources of suggestions are equal when they share the same function.
+impl<T> PartialEq for Suggestions<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T> fmt::Debug for Suggestions<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Suggestions").finish()
+    }
+}
+
+control_props! {
+    #[derive(Clone, Debug, Properties, PartialEq)]
+    pub struct AutocompleteProps<T: Clone + PartialEq + fmt::Display + 'static> {
+        /// The `name` attribute for this form element.
+        pub name: String,
+        /// The controlled selection of this component, displayed as the text of the input when it
+        /// changes.
+        #[prop_or_default]
+        pub value: Option<T>,
+        /// The source of the suggestions for the text of the input.
+        pub suggestions: Suggestions<T>,
+        /// The callback to be used for propagating the selected suggestion.
+        #[prop_or_else(Callback::noop)]
+        pub onselect: Callback<T>,
+        /// The callback to be used for propagating the text of the input as it changes.
+        #[prop_or_else(Callback::noop)]
+        pub oninput: Callback<String>,
+
+        #[prop_or_default]
+        pub classes: Option<Classes>,
+        /// The placeholder value for this component.
+        #[prop_or_default]
+        pub placeholder: String,
+        /// The size of this component.
+        #[prop_or_default]
+        pub size: Option<Size>,
+        /// Use rounded appearance.
+        #[prop_or_default]
+        pub rounded: bool,
+        /// Disable this component.
+        #[prop_or_default]
+        pub disabled: bool,
+        /// Only allow values chosen from the suggestions.
+        ///
+        /// When the input loses focus without a suggestion being chosen, its text reverts to the
+        /// current `value`, as propagated via `oninput`, and `Enter` chooses the first suggestion
+        /// unless another is highlighted.
+        #[prop_or_default]
+        pub must_choose: bool,
+        /// The delay in milliseconds after the text stops changing before suggestions are
+        /// requested.
+        #[prop_or_else(|| 300)]
+        pub debounce: u32,
+        /// The minimum number of characters of text for which suggestions are requested.
+        #[prop_or_else(|| 1)]
+        pub min_chars: usize,
+    }
+}
+
+/// The message type used by the `Autocomplete` component.
+pub enum AutocompleteMsg<T> {
+    /// The text of the input changed.
+    Input(String),
+    /// The text of the input stopped changing, so its suggestions are to be requested.
+    Fetch,
+    /// The suggestions of a request were received.
+    Loaded { generation: u64, suggestions: Vec<T> },
+    /// A navigation key was pressed within the input.
+    Key(String),
+    /// The suggestion at the given index was chosen.
+    Choose(usize),
+    /// The input gained focus.
+    Focus,
+    /// The input lost focus.
+    Blur,
+}
+
+/// An input with suggestions of values of type `T`, displayed in a dropdown menu as the user types.
+///
+/// [https://bulma.io/documentation/components/dropdown/](https://bulma.io/documentation/components/dropdown/)
+///
+/// Suggestions are requested from the `suggestions` source once the text of the input stops
+/// changing for the `debounce` delay, during which the input is displayed as loading. Responses to
+/// outdated requests are ignored. Suggestions are displayed via `Display`, with the typed text
+/// highlighted, and are chosen via clicks or the `Up`, `Down` & `Enter` keys; `Escape` closes the
+/// menu.
+///
+/// By default any text may be entered, as propagated via `oninput`. With the `must_choose` prop
+/// only chosen suggestions are accepted.
+///
+/// The `id`, `aria_*` & other common form control props apply to the input; its `id` also
+/// prefixes the `id`s of the suggestions.
+pub struct Autocomplete<T: Clone + PartialEq + fmt::Display + 'static> {
+    props: AutocompleteProps<T>,
+    link: ComponentLink<Self>,
+    /// The current text of the input.
+    text: String,
+    /// The current suggestions.
+    suggestions: Vec<T>,
+    /// The index of the highlighted suggestion.
+    active: Option<usize>,
+    /// Whether the menu of suggestions is open.
+    open: bool,
+    /// Whether suggestions are being requested.
+    loading: bool,
+    /// The debounce timer of the next request.
+    timeout: Option<TimeoutTask>,
+    /// The generation of the latest request, for ignoring the responses to outdated requests.
+    generation: u64,
+}
+
+impl<T: Clone + PartialEq + fmt::Display + 'static> Component for Autocomplete<T> {
+    type Message = AutocompleteMsg<T>;
+    type Properties = AutocompleteProps<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let text = props.value.as_ref().map(ToString::to_string).unwrap_or_default();
+        Self {
+            props,
+            link,
+            text,
+            suggestions: Vec::new(),
+            active: None,
+            open: false,
+            loading: false,
+            timeout: None,
+            generation: 0,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            AutocompleteMsg::Input(text) => {
+                self.text = text.clone();
+                self.props.oninput.emit(text);
+                // Invalidate any request in flight, as its text is outdated.
+                self.generation += 1;
+                if self.text.chars().count() < self.props.min_chars {
+                    self.timeout = None;
+                    self.loading = false;
+                    self.close();
+                } else {
+                    let duration = Duration::from_millis(self.props.debounce.into());
+                    self.timeout = Some(TimeoutService::spawn(duration, self.link.callback(|_| AutocompleteMsg::Fetch)));
+                    self.loading = true;
+                }
+                true
+            }
+            AutocompleteMsg::Fetch => {
+                self.timeout = None;
+                self.generation += 1;
+                let generation = self.generation;
+                let future = (self.props.suggestions.0)(self.text.clone());
+                let link = self.link.clone();
+                spawn_local(async move {
+                    let suggestions = future.await;
+                    link.send_message(AutocompleteMsg::Loaded { generation, suggestions });
+                });
+                false
+            }
+            AutocompleteMsg::Loaded { generation, suggestions } => {
+                if generation != self.generation {
+                    return false;
+                }
+                self.loading = false;
+                self.active = (self.props.must_choose && !suggestions.is_empty()).then_some(0);
+                self.open = !suggestions.is_empty();
+                self.suggestions = suggestions;
+                true
+            }
+            AutocompleteMsg::Key(key) => {
+                let len = self.suggestions.len();
+                match key.as_str() {
+                    "ArrowDown" if len > 0 => {
+                        self.active = Some(self.active.map(|index| (index + 1) % len).unwrap_or(0));
+                        self.open = true;
+                    }
+                    "ArrowUp" if len > 0 => {
+                        self.active = Some(self.active.map(|index| (index + len - 1) % len).unwrap_or(len - 1));
+                        self.open = true;
+                    }
+                    "Enter" => match self.active {
+                        Some(index) if self.open => return self.update(AutocompleteMsg::Choose(index)),
+                        _ => self.open = false,
+                    },
+                    "Escape" => self.open = false,
+                    _ => return false,
+                }
+                true
+            }
+            AutocompleteMsg::Choose(index) => {
+                let value = match self.suggestions.get(index) {
+                    Some(value) => value.clone(),
+                    None => return false,
+                };
+                self.text = value.to_string();
+                self.generation += 1;
+                self.timeout = None;
+                self.loading = false;
+                self.close();
+                self.props.oninput.emit(self.text.clone());
+                self.props.onselect.emit(value);
+                true
+            }
+            AutocompleteMsg::Focus => {
+                self.open = !self.suggestions.is_empty();
+                true
+            }
+            AutocompleteMsg::Blur => {
+                // Ignore pending requests, so that their suggestions don't open the menu beneath
+                // the unfocused input.
+                self.open = false;
+                self.generation += 1;
+                self.timeout = None;
+                self.loading = false;
+                if self.props.must_choose {
+                    let text = self.props.value.as_ref().map(ToString::to_string).unwrap_or_default();
+                    if text != self.text {
+                        self.text = text;
+                        self.close();
+                        self.props.oninput.emit(self.text.clone());
+                    }
+                }
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props.value != self.props.value {
+            self.text = props.value.as_ref().map(ToString::to_string).unwrap_or_default();
+        }
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let open = self.open && !self.suggestions.is_empty();
+        let mut classes = Classes::from("dropdown");
+        classes.push(&self.props.classes);
+        if open {
+            classes.push("is-active");
+        }
+        let mut control_classes = Classes::new();
+        if self.loading {
+            control_classes.push("is-loading");
+        }
+
+        // The ids linking the input to its suggestions, for assistive technologies.
+        let listbox_id = self.props.id.as_ref().map(|id| format!("{}-listbox", id));
+        let option_id = |index: usize| self.props.id.as_ref().map(|id| format!("{}-option-{}", id, index));
+        let mut attrs = vec![
+            ("role", "combobox".to_string()),
+            ("aria-autocomplete", "list".to_string()),
+            ("aria-expanded", open.to_string()),
+        ];
+        if let Some(listbox_id) = &listbox_id {
+            attrs.push(("aria-controls", listbox_id.clone()));
+        }
+        if let Some(id) = self.active.filter(|_| open).and_then(option_id) {
+            attrs.push(("aria-activedescendant", id));
+        }
+        attrs.extend(self.props.attrs.iter().cloned());
+
+        let intercept_enter = open && self.active.is_some();
+        let user_onkeydown = self.props.onkeydown.clone();
+        let onkeydown = self.link.batch_callback(move |event: KeyboardEvent| {
+            user_onkeydown.emit(event.clone());
+            let key = event.key();
+            match key.as_str() {
+                "ArrowDown" | "ArrowUp" => event.prevent_default(),
+                "Enter" if intercept_enter => event.prevent_default(),
+                "Enter" | "Escape" => (),
+                _ => return None,
+            }
+            Some(AutocompleteMsg::Key(key))
+        });
+
+        let items = self
+            .suggestions
+            .iter()
+            .enumerate()
+            .map(|(index, suggestion)| {
+                let active = self.active == Some(index);
+                let mut item_classes = Classes::from("dropdown-item");
+                if active {
+                    item_classes.push("is-active");
+                }
+                // Choose on `mousedown` rather than `click`, so that the input keeps its focus.
+                let onmousedown = self.link.callback(move |event: MouseEvent| {
+                    event.prevent_default();
+                    AutocompleteMsg::Choose(index)
+                });
+                html! {
+                    <a class=item_classes id=option_id(index) role="option" aria-selected=active.to_string() onmousedown=onmousedown>
+                        {highlight(&suggestion.to_string(), &self.text)}
+                    </a>
+                }
+            })
+            .collect::<Html>();
+
+        let user_onfocus = self.props.onfocus.clone();
+        let onfocus = self.link.callback(move |event| {
+            user_onfocus.emit(event);
+            AutocompleteMsg::Focus
+        });
+        let user_onblur = self.props.onblur.clone();
+        let onblur = self.link.callback(move |event| {
+            user_onblur.emit(event);
+            AutocompleteMsg::Blur
+        });
+
+        html! {
+            <div class=classes>
+                <div class="dropdown-trigger">
+                    <Control classes=control_classes>
+                        <Input
+                            name=self.props.name.clone()
+                            value=self.text.clone()
+                            update=self.link.callback(AutocompleteMsg::Input)
+                            placeholder=self.props.placeholder.clone()
+                            size=self.props.size.clone()
+                            rounded=self.props.rounded
+                            loading=self.loading
+                            disabled=self.props.disabled
+                            id=self.props.id.clone()
+                            autocomplete=Some("off".to_string())
+                            required=self.props.required
+                            autofocus=self.props.autofocus
+                            aria_label=self.props.aria_label.clone()
+                            aria_describedby=self.props.aria_describedby.clone()
+                            attrs=attrs
+                            onkeydown=onkeydown
+                            onfocus=onfocus
+                            onblur=onblur
+                            />
+                    </Control>
+                </div>
+                <div class="dropdown-menu">
+                    <div class="dropdown-content" id=listbox_id role="listbox">
+                        {items}
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + fmt::Display + 'static> Autocomplete<T> {
+    /// Close the menu, discarding the current suggestions.
+    fn close(&mut self) {
+        self.open = false;
+        self.active = None;
+        self.suggestions.clear();
+    }
+}
+
+/// Render the given label with the first case-insensitive match of the given text in bold.
+fn highlight(label: &str, text: &str) -> Html {
+    let text = text.trim().to_lowercase();
+    let lower = label.to_lowercase();
+    // Matches are only located when lowercasing preserves the byte offsets of the label.
+    let start = match (text.is_empty() || lower.len() != label.len(), lower.find(&text)) {
+        (false, Some(start)) => start,
+        _ => return html! {label},
+    };
+    let end = start + text.len();
+    if !label.is_char_boundary(start) || !label.is_char_boundary(end) {
+        return html! {label};
+    }
+    html! {
+        <>
+            {&label[..start]}
+            <strong>{&label[start..end]}</strong>
+            {&label[end..]}
+        </>
+    }
+}
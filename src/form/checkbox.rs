@@ -1,21 +1,41 @@
+use web_sys::HtmlInputElement;
 use yew::prelude::*;
 use yewtil::NeqAssign;
 
-#[derive(Clone, Debug, Properties, PartialEq)]
-pub struct CheckboxProps {
-    /// The `name` attribute for this form element.
-    pub name: String,
-    /// The controlled value of this form element.
-    pub checked: bool,
-    /// The callback to be used for propagating changes to this element's value.
-    pub update: Callback<bool>,
-    #[prop_or_default]
-    pub children: Children,
-    #[prop_or_default]
-    pub classes: Option<Classes>,
-    /// Disable this component.
-    #[prop_or_default]
-    pub disabled: bool,
+use super::validation::{RuleValue, Rules};
+use super::with_control_attrs;
+
+control_props! {
+    #[derive(Clone, Debug, Properties, PartialEq)]
+    pub struct CheckboxProps {
+        /// The `name` attribute for this form element.
+        pub name: String,
+        /// The controlled value of this form element.
+        pub checked: bool,
+        /// The callback to be used for propagating changes to this element's value.
+        pub update: Callback<bool>,
+        #[prop_or_default]
+        pub children: Children,
+        #[prop_or_default]
+        pub classes: Option<Classes>,
+        /// Disable this component.
+        #[prop_or_default]
+        pub disabled: bool,
+        /// Display this component in the indeterminate state, regardless of its checked state.
+        ///
+        /// This is purely visual, and is typically used for a checkbox controlling a group of
+        /// checkboxes of which only some are checked.
+        #[prop_or_default]
+        pub indeterminate: bool,
+        /// Validation rules for the value of this component, adding the `is-success` or `is-danger`
+        /// class once the value has been changed.
+        #[prop_or_default]
+        pub rules: Option<Rules>,
+        /// The callback to be used for propagating the validation result of each new value, given
+        /// the `rules` of this component.
+        #[prop_or_else(Callback::noop)]
+        pub onvalidate: Callback<Result<(), String>>,
+    }
 }
 
 /// The 2-state checkbox in its native format.
@@ -28,6 +48,9 @@ pub struct CheckboxProps {
 pub struct Checkbox {
     props: CheckboxProps,
     link: ComponentLink<Self>,
+    /// Whether the value has been changed by the user, after which its validity is displayed.
+    touched: bool,
+    input_ref: NodeRef,
 }
 
 impl Component for Checkbox {
@@ -35,10 +58,19 @@ impl Component for Checkbox {
     type Properties = CheckboxProps;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Self { props, link }
+        Self {
+            props,
+            link,
+            touched: false,
+            input_ref: NodeRef::default(),
+        }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        if let Some(rules) = &self.props.rules {
+            self.props.onvalidate.emit(rules.check(&msg.rule_value()));
+        }
+        self.touched = true;
         self.props.update.emit(msg);
         false
     }
@@ -47,19 +79,33 @@ impl Component for Checkbox {
         self.props.neq_assign(props)
     }
 
+    fn rendered(&mut self, _first_render: bool) {
+        // The indeterminate state is only available as a DOM property, not as an attribute.
+        if let Some(input) = self.input_ref.cast::<HtmlInputElement>() {
+            input.set_indeterminate(self.props.indeterminate);
+        }
+    }
+
     fn view(&self) -> Html {
         let mut classes = Classes::from("checkbox");
         classes.push(&self.props.classes);
+        if let (true, Some(rules)) = (self.touched, &self.props.rules) {
+            classes.push(rules.class(&self.props.checked.rule_value()));
+        }
         let checked = self.props.checked;
+        let input = html! {
+            <input
+                ref=self.input_ref.clone()
+                type="checkbox"
+                checked=self.props.checked
+                name=self.props.name.clone()
+                onclick=self.link.callback(move |_| !checked)
+                disabled=self.props.disabled
+                />
+        };
         html! {
             <label class=classes disabled=self.props.disabled>
-                <input
-                    type="checkbox"
-                    checked=self.props.checked
-                    name=self.props.name.clone()
-                    onclick=self.link.callback(move |_| !checked)
-                    disabled=self.props.disabled
-                    />
+                {with_control_attrs(input, control_attrs!(self.props))}
                 {self.props.children.clone()}
             </label>
         }
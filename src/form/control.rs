@@ -15,6 +15,12 @@ pub struct ControlProps {
     /// A modifier to have the controlled element fill up the remaining space.
     #[prop_or_default]
     pub expanded: bool,
+    /// An icon displayed on the left of the control, adding the `has-icons-left` class.
+    #[prop_or_default]
+    pub icon_left: Option<Html>,
+    /// An icon displayed on the right of the control, adding the `has-icons-right` class.
+    #[prop_or_default]
+    pub icon_right: Option<Html>,
 }
 
 /// A container with which you can wrap the form controls.
@@ -46,10 +52,26 @@ impl Component for Control {
         if self.props.expanded {
             classes.push("is-expanded");
         }
+        let icon_left = match &self.props.icon_left {
+            Some(icon) => {
+                classes.push("has-icons-left");
+                html! {<span class="icon is-left">{icon.clone()}</span>}
+            }
+            None => html! {},
+        };
+        let icon_right = match &self.props.icon_right {
+            Some(icon) => {
+                classes.push("has-icons-right");
+                html! {<span class="icon is-right">{icon.clone()}</span>}
+            }
+            None => html! {},
+        };
         let tag = self.props.tag.clone();
         html! {
             <@{tag} class=classes>
                 {self.props.children.clone()}
+                {icon_left}
+                {icon_right}
             </@>
         }
     }
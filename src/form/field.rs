@@ -11,6 +11,12 @@ pub struct FieldProps {
     /// A text label for the field.
     #[prop_or_default]
     pub label: Option<String>,
+    /// The `id` of this field's control.
+    ///
+    /// This links the label to the control via its `for` attribute, and gives the help message the
+    /// `id` given by `Field::help_id`, for the `aria_describedby` prop of the control.
+    #[prop_or_default]
+    pub control_id: Option<String>,
     /// Extra classes for the label container.
     #[prop_or_default]
     pub label_classes: Option<Classes>,
@@ -23,12 +29,6 @@ pub struct FieldProps {
     /// A convenience bool to add the `is-danger` class to the help classes when `true`.
     #[prop_or_default]
     pub help_has_error: bool,
-    /// Has icons on the left of the field's controls.
-    #[prop_or_default]
-    pub icons_left: bool,
-    /// Has icons on the right of the field's controls.
-    #[prop_or_default]
-    pub icons_right: bool,
     /// Allow addons to the field's controls.
     #[prop_or_default]
     pub addons: bool,
@@ -73,12 +73,6 @@ impl Component for Field {
     fn view(&self) -> Html {
         let mut classes = Classes::from("field");
         classes.push(&self.props.classes);
-        if self.props.icons_left {
-            classes.push("has-icons-left");
-        }
-        if self.props.icons_right {
-            classes.push("has-icons-right");
-        }
         if self.props.addons {
             classes.push("has-addons");
         }
@@ -104,26 +98,27 @@ impl Component for Field {
                         label_classes.push("field-label");
                         html! {
                             <div class=label_classes>
-                                <label class="label">{label_content.clone()}</label>
+                                <label class="label" for=self.props.control_id.clone()>{label_content.clone()}</label>
                             </div>
                         }
                     } else {
                         label_classes.push("label");
-                        html! {<label class=label_classes>{label_content.clone()}</label>}
+                        html! {<label class=label_classes for=self.props.control_id.clone()>{label_content.clone()}</label>}
                     }
                 }
                 None => {
                     if self.props.horizontal {
-                        html! {<div class="field-label"><label class="label">{label_content.clone()}</label></div>}
+                        html! {<div class="field-label"><label class="label" for=self.props.control_id.clone()>{label_content.clone()}</label></div>}
                     } else {
-                        html! {<label class="label">{label_content.clone()}</label>}
+                        html! {<label class="label" for=self.props.control_id.clone()>{label_content.clone()}</label>}
                     }
                 }
             },
             None => html! {},
         };
 
-        // Build the help label if present.
+        // Build the help message if present.
+        let help_id = self.props.control_id.as_deref().map(Field::help_id);
         let help = match &self.props.help {
             Some(help_content) => match &self.props.help_classes {
                 Some(help_classes_str) => {
@@ -132,14 +127,14 @@ impl Component for Field {
                     if self.props.help_has_error {
                         help_classes.push("is-danger");
                     }
-                    html! {<label class=help_classes>{help_content.clone()}</label>}
+                    html! {<p class=help_classes id=help_id.clone()>{help_content.clone()}</p>}
                 }
                 None => {
                     let mut help_classes = Classes::from("help");
                     if self.props.help_has_error {
                         help_classes.push("is-danger");
                     }
-                    html! {<label class=help_classes>{help_content.clone()}</label>}
+                    html! {<p class=help_classes id=help_id.clone()>{help_content.clone()}</p>}
                 }
             },
             None => html! {},
@@ -161,6 +156,13 @@ impl Component for Field {
     }
 }
 
+impl Field {
+    /// The `id` of the help message of a field, given the `id` of the field's control.
+    pub fn help_id(control_id: &str) -> String {
+        format!("{}-help", control_id)
+    }
+}
+
 /// The two alignment options available for field addons.
 ///
 /// https://bulma.io/documentation/form/general/
@@ -1,53 +1,93 @@
 #![allow(clippy::redundant_closure_call)]
 
-use web_sys::File as SysFile;
+use std::collections::HashMap;
+
+use gloo_file::callbacks::FileReader;
+use web_sys::{File as SysFile, HtmlInputElement};
 use yew::events::ChangeData;
 use yew::prelude::*;
 use yewtil::NeqAssign;
 
+use super::file_reader::{read_file, FileContent, FileLoadError, LoadedFile, ReadAs};
+use super::with_control_attrs;
 use crate::{Alignment, Size};
 
-#[derive(Clone, Debug, Properties, PartialEq)]
-pub struct FileProps {
-    /// The `name` attribute for this form element.
-    pub name: String,
-    /// The controlled form value for the currently selected files.
-    pub files: Vec<SysFile>,
-    /// The callback to be used for propagating changes to this form element.
-    pub update: Callback<Vec<SysFile>>,
-
-    /// The display text for the file selector.
-    #[prop_or_else(|| "Choose a file...".into())]
-    pub selector_label: String,
-    /// The HTML contents to use for the file selector icon.
-    #[prop_or_default]
-    pub selector_icon: Html,
-
-    #[prop_or_default]
-    pub classes: Option<Classes>,
-    /// An option to control if file names will be displayed; if a value is provided, then the
-    /// `has-name` class will be added to this form element and the given value will be used as a
-    /// placeholder until files are selected.
-    #[prop_or_default]
-    pub has_name: Option<String>,
-    /// Move the CTA element to the right side of the component.
-    #[prop_or_default]
-    pub right: bool,
-    /// Expand the file display name to the full width of the parent.
-    #[prop_or_default]
-    pub fullwidth: bool,
-    /// Display as a boxed block.
-    #[prop_or_default]
-    pub boxed: bool,
-    /// Allow multiple files to be selected.
-    #[prop_or_default]
-    pub multiple: bool,
-    /// The size of this component.
-    #[prop_or_default]
-    pub size: Option<Size>,
-    /// The alignment of this component within its parent.
-    #[prop_or_default]
-    pub alignment: Option<Alignment>,
+control_props! {
+    #[derive(Clone, Debug, Properties, PartialEq)]
+    pub struct FileProps {
+        /// The `name` attribute for this form element.
+        pub name: String,
+        /// The controlled form value for the currently selected files.
+        pub files: Vec<SysFile>,
+        /// The callback to be used for propagating changes to this form element.
+        pub update: Callback<Vec<SysFile>>,
+        /// Read the contents of the selected files, propagating them via `onload`.
+        #[prop_or_default]
+        pub read_as: Option<ReadAs>,
+        /// Read the bytes of the selected files in chunks of this many bytes, propagating each
+        /// chunk via `onload` as it is read; this only applies to `ReadAs::Bytes`.
+        #[prop_or_default]
+        pub chunk_size: Option<u64>,
+        /// The callback to be used for propagating the contents of the selected files as they are
+        /// read, or why they could not be read.
+        #[prop_or_else(Callback::noop)]
+        pub onload: Callback<Result<LoadedFile, FileLoadError>>,
+        /// Cancel the reads in progress whenever this value changes, e.g. by incrementing it when the
+        /// user aborts an upload.
+        #[prop_or_default]
+        pub cancel: u32,
+
+        /// The display text for the file selector.
+        #[prop_or_else(|| "Choose a file...".into())]
+        pub selector_label: String,
+        /// The HTML contents to use for the file selector icon.
+        #[prop_or_default]
+        pub selector_icon: Html,
+
+        #[prop_or_default]
+        pub classes: Option<Classes>,
+        /// An option to control if file names will be displayed; if a value is provided, then the
+        /// `has-name` class will be added to this form element and the given value will be used as
+        /// a placeholder until files are selected. The names of selected files are always
+        /// displayed.
+        #[prop_or_default]
+        pub has_name: Option<String>,
+        /// Display the number of selected files via `summary_label` instead of their names, when
+        /// more than one file is selected.
+        #[prop_or_default]
+        pub summary: bool,
+        /// The text displayed for multiple selected files in `summary` mode, with `{count}`
+        /// replaced by the number of files.
+        #[prop_or_else(|| "{count} files selected".into())]
+        pub summary_label: String,
+        /// Move the CTA element to the right side of the component.
+        #[prop_or_default]
+        pub right: bool,
+        /// Expand the file display name to the full width of the parent.
+        #[prop_or_default]
+        pub fullwidth: bool,
+        /// Display as a boxed block.
+        #[prop_or_default]
+        pub boxed: bool,
+        /// Allow multiple files to be selected.
+        #[prop_or_default]
+        pub multiple: bool,
+        /// The accepted files, as MIME types such as `image/png`, MIME type wildcards such as
+        /// `image/*`, or file extensions such as `.pdf`, for the `accept` attribute of this form
+        /// element.
+        #[prop_or_default]
+        pub accept: Vec<String>,
+        /// The `capture` attribute for this form element, i.e. `user` or `environment`, for
+        /// capturing new files via the camera or microphone of mobile devices.
+        #[prop_or_default]
+        pub capture: Option<String>,
+        /// The size of this component.
+        #[prop_or_default]
+        pub size: Option<Size>,
+        /// The alignment of this component within its parent.
+        #[prop_or_default]
+        pub alignment: Option<Alignment>,
+    }
 }
 
 /// A custom file upload input.
@@ -56,33 +96,103 @@ pub struct FileProps {
 ///
 /// All YBC form components are controlled components. This means that the value of the field must
 /// be provided from a parent component, and changes to this component are propagated to the parent
-/// component via callback.
+/// component via callback. When `files` is set to an empty list, the native file input is also
+/// cleared.
+///
+/// With the `read_as` prop, the contents of selected files are read & propagated via `onload`,
+/// possibly in chunks given the `chunk_size`. Reads in progress are cancelled when other files are
+/// selected, when `files` is set to an empty list or `read_as` to `None`, when the `cancel` prop
+/// changes, and when the component is destroyed.
 pub struct File {
     props: FileProps,
     link: ComponentLink<Self>,
+    input_ref: NodeRef,
+    /// The reads in progress & their files, by the index of the file; dropping a reader cancels
+    /// its read.
+    readers: HashMap<usize, (SysFile, FileReader)>,
+    /// The generation of the current reads, for ignoring the results of cancelled reads.
+    generation: u64,
+}
+
+/// The message type used by the `File` component.
+pub enum FileMsg {
+    /// Files were selected.
+    Select(Vec<SysFile>),
+    /// A read of the file at the given index completed.
+    Loaded {
+        generation: u64,
+        index: usize,
+        result: Result<FileContent, String>,
+    },
 }
 
 impl Component for File {
-    type Message = Vec<SysFile>;
+    type Message = FileMsg;
     type Properties = FileProps;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Self { props, link }
+        Self {
+            props,
+            link,
+            input_ref: NodeRef::default(),
+            readers: HashMap::new(),
+            generation: 0,
+        }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
-        self.props.update.emit(msg);
+        match msg {
+            FileMsg::Select(files) => {
+                self.cancel_reads();
+                if self.props.read_as.is_some() {
+                    for (index, file) in files.iter().enumerate() {
+                        self.read(index, file, 0);
+                    }
+                }
+                self.props.update.emit(files);
+            }
+            FileMsg::Loaded { generation, index, result } => {
+                if generation != self.generation {
+                    return false;
+                }
+                let file = match self.readers.remove(&index) {
+                    Some((file, _)) => file,
+                    None => return false,
+                };
+                match result {
+                    Ok(content) => {
+                        // Read the next chunk, if any.
+                        if let FileContent::Chunk { offset, bytes, last: false } = &content {
+                            self.read(index, &file, offset + bytes.len() as u64);
+                        }
+                        self.props.onload.emit(Ok(LoadedFile { file, content }));
+                    }
+                    Err(message) => self.props.onload.emit(Err(FileLoadError { file, message })),
+                }
+            }
+        }
         false
     }
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        let cleared = props.files.is_empty() && !self.props.files.is_empty();
+        if cleared {
+            if let Some(input) = self.input_ref.cast::<HtmlInputElement>() {
+                input.set_value("");
+            }
+        }
+        let stopped = props.read_as.is_none() && self.props.read_as.is_some();
+        if cleared || stopped || props.cancel != self.props.cancel {
+            self.cancel_reads();
+        }
         self.props.neq_assign(props)
     }
 
     fn view(&self) -> Html {
         let mut classes = Classes::from("file");
         classes.push(&self.props.classes);
-        if self.props.has_name.is_some() {
+        let filename = self.filename();
+        if filename.is_some() {
             classes.push("has-name");
         }
         if self.props.right {
@@ -100,27 +210,31 @@ impl Component for File {
         if let Some(alignment) = &self.props.alignment {
             classes.push(&alignment.to_string());
         }
-        let filenames = self
-            .props
-            .files
-            .iter()
-            .map(|file| html! {<span class="file-name">{file.name()}</span>})
-            .collect::<Vec<_>>();
+        let filename = filename
+            .map(|name| html! {<span class="file-name">{name}</span>})
+            .unwrap_or_default();
+        let accept = (!self.props.accept.is_empty()).then(|| self.props.accept.join(","));
+        let input = html! {
+            <input
+                type="file"
+                class="file-input"
+                name=self.props.name.clone()
+                multiple=self.props.multiple
+                accept=accept
+                capture=self.props.capture.clone()
+                ref=self.input_ref.clone()
+                onchange=self.link.callback(|data: ChangeData| match data {
+                    ChangeData::Files(list) => FileMsg::Select((0..list.length()).into_iter()
+                        .filter_map(|idx| list.item(idx))
+                        .collect::<Vec<_>>()),
+                    _ => unreachable!("invariant violation: received non-file change event from a file input element"),
+                })
+                />
+        };
         html! {
             <div class=classes>
                 <label class="file-label">
-                    <input
-                        type="file"
-                        class="file-input"
-                        name=self.props.name.clone()
-                        multiple=self.props.multiple
-                        onchange=self.link.callback(|data: ChangeData| match data {
-                            ChangeData::Files(list) => (0..list.length()).into_iter()
-                                .filter_map(|idx| list.item(idx))
-                                .collect::<Vec<_>>(),
-                            _ => unreachable!("invariant violation: received non-file change event from a file input element"),
-                        })
-                        />
+                    {with_control_attrs(input, control_attrs!(self.props))}
                     <span class="file-cta">
                         <span class="file-icon">
                             {self.props.selector_icon.clone()}
@@ -129,9 +243,41 @@ impl Component for File {
                             {self.props.selector_label.clone()}
                         </span>
                     </span>
-                    {filenames}
+                    {filename}
                 </label>
             </div>
         }
     }
 }
+
+impl File {
+    /// Start reading the given file at the given index, from the given offset.
+    fn read(&mut self, index: usize, file: &SysFile, offset: u64) {
+        let read_as = match self.props.read_as {
+            Some(read_as) => read_as,
+            None => return,
+        };
+        let generation = self.generation;
+        let link = self.link.clone();
+        let reader = read_file(file, read_as, self.props.chunk_size, offset, move |result| {
+            link.send_message(FileMsg::Loaded { generation, index, result });
+        });
+        self.readers.insert(index, (file.clone(), reader));
+    }
+
+    /// Cancel all reads in progress.
+    fn cancel_reads(&mut self) {
+        self.readers.clear();
+        self.generation += 1;
+    }
+
+    /// The text of the file name element: the names or number of the selected files, or otherwise
+    /// the `has_name` placeholder, if any.
+    fn filename(&self) -> Option<String> {
+        match self.props.files.as_slice() {
+            [] => self.props.has_name.clone(),
+            files if self.props.summary && files.len() > 1 => Some(self.props.summary_label.replace("{count}", &files.len().to_string())),
+            files => Some(files.iter().map(SysFile::name).collect::<Vec<_>>().join(", ")),
+        }
+    }
+}
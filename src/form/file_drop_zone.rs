@@ -0,0 +1,407 @@
+#![allow(clippy::unnecessary_operation)]
+
+use derive_more::Display;
+use web_sys::{File as SysFile, FileList, HtmlInputElement, Url};
+use yew::events::ChangeData;
+use yew::prelude::*;
+use yewtil::NeqAssign;
+
+use super::with_control_attrs;
+use crate::Progress;
+
+control_props! {
+    #[derive(Clone, Debug, Properties, PartialEq)]
+    pub struct FileDropZoneProps {
+        /// The `name` attribute for this form element.
+        pub name: String,
+        /// The controlled form value for the currently selected files.
+        pub files: Vec<SysFile>,
+        /// The callback to be used for propagating changes to this form element.
+        pub update: Callback<Vec<SysFile>>,
+        /// The callback to be used for propagating why a dropped or chosen file was not accepted.
+        #[prop_or_else(Callback::noop)]
+        pub onerror: Callback<FileRejection>,
+
+        /// The accepted files, as MIME types such as `image/png`, MIME type wildcards such as
+        /// `image/*`, or file extensions such as `.pdf`; all files are accepted when empty.
+        #[prop_or_default]
+        pub accept: Vec<String>,
+        /// The maximum size of each file, in bytes.
+        #[prop_or_default]
+        pub max_size: Option<u64>,
+        /// Allow multiple files to be selected, adding further files to those already selected.
+        #[prop_or_default]
+        pub multiple: bool,
+        /// Display thumbnails of the selected images.
+        #[prop_or_else(|| true)]
+        pub thumbnails: bool,
+        /// The upload progress of each of the selected files, from `0.0` to `1.0`, in the order of
+        /// `files`; a `Progress` bar is displayed for each file with a value.
+        #[prop_or_default]
+        pub progress: Vec<Option<f32>>,
+
+        /// The display text of the drop zone.
+        #[prop_or_else(|| "Drop files here or click to choose...".into())]
+        pub label: String,
+        /// The HTML contents to use for the drop zone icon.
+        #[prop_or_default]
+        pub icon: Html,
+        #[prop_or_default]
+        pub classes: Option<Classes>,
+        /// Disable this component.
+        #[prop_or_default]
+        pub disabled: bool,
+    }
+}
+
+/// The reason a file was not accepted by a `FileDropZone`.
+#[derive(Clone, Debug, Display, PartialEq)]
+pub enum FileRejection {
+    /// The file with the given name is not of an accepted type.
+    #[display(fmt = "\"{}\" is not an accepted type of file", _0)]
+    Type(String),
+    /// The file with the given name is larger than the maximum size.
+    #[display(fmt = "\"{}\" is larger than {}", name, "format_size(*max as f64)")]
+    Size { name: String, max: u64 },
+}
+
+impl std::error::Error for FileRejection {}
+
+/// The message type used by the `FileDropZone` component.
+pub enum FileDropZoneMsg {
+    /// Files were dragged into the drop zone.
+    DragEnter,
+    /// Files were dragged out of the drop zone.
+    DragLeave,
+    /// Files were dropped onto the drop zone or chosen via the file dialog.
+    Add(Option<FileList>),
+    /// The file at the given index was removed.
+    Remove(usize),
+    /// The file input gained or lost focus.
+    Focus(bool),
+}
+
+/// A zone onto which files can be dragged & dropped, or clicked to choose files, listing the
+/// selected files.
+///
+/// [https://bulma.io/documentation/form/file/](https://bulma.io/documentation/form/file/)
+///
+/// Files which are not of an `accept`ed type or are larger than `max_size` are not added, with
+/// the reason propagated via `onerror`. Each selected file is listed with its size, a thumbnail
+/// when it is an image, a delete button & a `Progress` bar given its `progress`, for displaying
+/// the progress of uploads made by the parent component.
+///
+/// The file input of the zone is only hidden visually, so that it can be focused via the keyboard
+/// & opened via `Enter` or `Space`, and the common form control props apply to it.
+///
+/// All YBC form components are controlled components. This means that the value of the field must
+/// be provided from a parent component, and changes to this component are propagated to the parent
+/// component via callback.
+pub struct FileDropZone {
+    props: FileDropZoneProps,
+    link: ComponentLink<Self>,
+    /// The object URLs of the thumbnails of the selected files, which are revoked once the files
+    /// change.
+    thumbnails: Vec<Option<String>>,
+    /// The depth of `dragenter` events over the drop zone & its children.
+    drag_depth: u32,
+    /// Whether the file input has focus.
+    focused: bool,
+    input_ref: NodeRef,
+}
+
+impl Component for FileDropZone {
+    type Message = FileDropZoneMsg;
+    type Properties = FileDropZoneProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let mut zone = Self {
+            props,
+            link,
+            thumbnails: Vec::new(),
+            drag_depth: 0,
+            focused: false,
+            input_ref: NodeRef::default(),
+        };
+        zone.update_thumbnails();
+        zone
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            FileDropZoneMsg::DragEnter => {
+                self.drag_depth += 1;
+                self.drag_depth == 1
+            }
+            FileDropZoneMsg::DragLeave => {
+                self.drag_depth = self.drag_depth.saturating_sub(1);
+                self.drag_depth == 0
+            }
+            FileDropZoneMsg::Add(list) => {
+                self.drag_depth = 0;
+                let list = match list {
+                    Some(list) if !self.props.disabled => list,
+                    _ => return true,
+                };
+                let added = (0..list.length())
+                    .filter_map(|idx| list.item(idx))
+                    .filter(|file| self.check(file))
+                    .collect::<Vec<_>>();
+                // Clear the native input once its files are taken, so that a removed file can be chosen
+                // again.
+                if let Some(input) = self.input_ref.cast::<HtmlInputElement>() {
+                    input.set_value("");
+                }
+                if !added.is_empty() {
+                    let files = if self.props.multiple {
+                        self.props.files.iter().cloned().chain(added).collect()
+                    } else {
+                        added.into_iter().take(1).collect()
+                    };
+                    self.props.update.emit(files);
+                }
+                true
+            }
+            FileDropZoneMsg::Remove(index) => {
+                let mut files = self.props.files.clone();
+                if index < files.len() {
+                    files.remove(index);
+                    self.props.update.emit(files);
+                }
+                false
+            }
+            FileDropZoneMsg::Focus(focused) => {
+                self.focused = focused;
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        let files_changed = props.files != self.props.files || props.thumbnails != self.props.thumbnails;
+        let changed = self.props.neq_assign(props);
+        if files_changed {
+            self.update_thumbnails();
+        }
+        changed
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = Classes::from("box has-text-centered");
+        classes.push(&self.props.classes);
+        if self.drag_depth > 0 {
+            classes.push("has-background-light");
+        }
+        let accept = (!self.props.accept.is_empty()).then(|| self.props.accept.join(","));
+        let input = html! {
+            <input
+                type="file"
+                name=self.props.name.clone()
+                accept=accept
+                multiple=self.props.multiple
+                disabled=self.props.disabled
+                style="position:absolute;top:0;left:0;width:100%;height:100%;opacity:0;cursor:pointer;"
+                ref=self.input_ref.clone()
+                onfocus=self.link.callback(|_| FileDropZoneMsg::Focus(true))
+                onblur=self.link.callback(|_| FileDropZoneMsg::Focus(false))
+                onchange=self.link.callback(|data: ChangeData| match data {
+                    ChangeData::Files(list) => FileDropZoneMsg::Add(Some(list)),
+                    _ => unreachable!("invariant violation: received non-file change event from a file input element"),
+                })
+                />
+        };
+        // The file input is only hidden visually, so that it can be focused & opened via the
+        // keyboard; its focus is shown on the drop zone instead.
+        let style = if self.focused {
+            "border:2px dashed #485fc7;box-shadow:none;"
+        } else {
+            "border:2px dashed #dbdbdb;box-shadow:none;"
+        };
+        let ondragenter = self.link.callback(|event: DragEvent| {
+            event.prevent_default();
+            FileDropZoneMsg::DragEnter
+        });
+        // Files may only be dropped while `dragover` events are cancelled.
+        let ondragover = Callback::from(|event: DragEvent| event.prevent_default());
+        let ondragleave = self.link.callback(|_| FileDropZoneMsg::DragLeave);
+        let ondrop = self.link.callback(|event: DragEvent| {
+            event.prevent_default();
+            FileDropZoneMsg::Add(event.data_transfer().and_then(|data| data.files()))
+        });
+
+        let files = self
+            .props
+            .files
+            .iter()
+            .enumerate()
+            .map(|(index, file)| self.view_file(index, file))
+            .collect::<Html>();
+        html! {
+            <div>
+                <div
+                    class=classes
+                    style=style
+                    ondragenter=ondragenter
+                    ondragover=ondragover
+                    ondragleave=ondragleave
+                    ondrop=ondrop
+                >
+                    <label style="display:block;position:relative;cursor:pointer;">
+                        {with_control_attrs(input, control_attrs!(self.props))}
+                        <span class="icon is-large">{self.props.icon.clone()}</span>
+                        <span class="is-block">{self.props.label.clone()}</span>
+                    </label>
+                </div>
+                {files}
+            </div>
+        }
+    }
+
+    fn destroy(&mut self) {
+        self.revoke_thumbnails();
+    }
+}
+
+impl FileDropZone {
+    /// Check whether the given file may be added, propagating the reason if not.
+    fn check(&self, file: &SysFile) -> bool {
+        let name = file.name();
+        if !accepts(&self.props.accept, &name, &file.type_()) {
+            self.props.onerror.emit(FileRejection::Type(name));
+            return false;
+        }
+        match self.props.max_size {
+            Some(max) if file.size() > max as f64 => {
+                self.props.onerror.emit(FileRejection::Size { name, max });
+                false
+            }
+            _ => true,
+        }
+    }
+
+    /// Recreate the thumbnails of the selected images, revoking those of the previous files.
+    fn update_thumbnails(&mut self) {
+        self.revoke_thumbnails();
+        let thumbnails = self.props.thumbnails;
+        self.thumbnails = self
+            .props
+            .files
+            .iter()
+            .map(|file| {
+                if !thumbnails || !file.type_().starts_with("image/") {
+                    return None;
+                }
+                Url::create_object_url_with_blob(file).ok()
+            })
+            .collect();
+    }
+
+    /// Revoke the object URLs of the thumbnails.
+    fn revoke_thumbnails(&mut self) {
+        for url in self.thumbnails.drain(..).flatten() {
+            let _ = Url::revoke_object_url(&url);
+        }
+    }
+
+    /// Render the given selected file.
+    fn view_file(&self, index: usize, file: &SysFile) -> Html {
+        let thumbnail = match self.thumbnails.get(index).cloned().flatten() {
+            Some(url) => html! {
+                <figure class="media-left image is-48x48">
+                    <img src=url alt=file.name() style="object-fit:cover;height:100%;" />
+                </figure>
+            },
+            None => html! {},
+        };
+        let progress = match self.props.progress.get(index).copied().flatten() {
+            Some(value) => html! {<Progress classes=classes!("is-small", "is-primary") max=1.0 value=value />},
+            None => html! {},
+        };
+        let delete = match self.props.disabled {
+            true => html! {},
+            false => {
+                let label = format!("Remove {}", file.name());
+                let onclick = self.link.callback(move |_| FileDropZoneMsg::Remove(index));
+                html! {
+                    <div class="media-right">
+                        <button type="button" class="delete" aria-label=label onclick=onclick></button>
+                    </div>
+                }
+            }
+        };
+        html! {
+            <div class="media">
+                {thumbnail}
+                <div class="media-content">
+                    <p>
+                        {file.name()}{" "}
+                        <small class="has-text-grey">{format_size(file.size())}</small>
+                    </p>
+                    {progress}
+                </div>
+                {delete}
+            </div>
+        }
+    }
+}
+
+/// Check whether a file with the given name & MIME type matches any of the given `accept`
+/// specifiers, as for the `accept` attribute of file inputs.
+fn accepts(accept: &[String], name: &str, mime: &str) -> bool {
+    if accept.is_empty() {
+        return true;
+    }
+    let name = name.to_lowercase();
+    let mime = mime.to_lowercase();
+    accept.iter().map(|spec| spec.trim().to_lowercase()).any(|spec| {
+        if spec.starts_with('.') {
+            name.ends_with(&spec)
+        } else if let Some(group) = spec.strip_suffix("/*") {
+            mime.split('/').next() == Some(group)
+        } else {
+            mime == spec
+        }
+    })
+}
+
+/// Format the given number of bytes for display, e.g. `1.5 MB`.
+fn format_size(bytes: f64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024.0 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_any_file_without_specifiers() {
+        assert!(accepts(&[], "notes.txt", "text/plain"));
+    }
+
+    #[test]
+    fn accepts_extensions_mime_types_and_wildcards() {
+        let accept = vec![".PDF".to_string(), "image/*".to_string(), "text/csv".to_string()];
+        assert!(accepts(&accept, "Report.pdf", ""));
+        assert!(accepts(&accept, "photo.jpeg", "image/jpeg"));
+        assert!(accepts(&accept, "data", "text/csv"));
+        assert!(!accepts(&accept, "notes.txt", "text/plain"));
+        assert!(!accepts(&accept, "video.mp4", "video/mp4"));
+    }
+
+    #[test]
+    fn formats_sizes() {
+        assert_eq!(format_size(512.0), "512 B");
+        assert_eq!(format_size(1536.0), "1.5 KB");
+        assert_eq!(format_size(5.0 * 1024.0 * 1024.0), "5.0 MB");
+    }
+}
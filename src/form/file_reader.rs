@@ -0,0 +1,77 @@
+use derive_more::Display;
+use gloo_file::callbacks::{self, FileReader};
+use gloo_file::Blob;
+use web_sys::File as SysFile;
+
+/// How the contents of the selected files of a `File` component are read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReadAs {
+    /// Read files as UTF-8 text.
+    Text,
+    /// Read files as bytes, in chunks when a chunk size is given.
+    Bytes,
+    /// Read files as base64 encoded `data:` URLs.
+    DataUrl,
+}
+
+/// The contents of a file, as read by a `File` component.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FileContent {
+    /// The text of the file.
+    Text(String),
+    /// The bytes of the file.
+    Bytes(Vec<u8>),
+    /// The `data:` URL of the file.
+    DataUrl(String),
+    /// A chunk of the bytes of a file which is read in chunks, starting at the given byte offset.
+    Chunk { offset: u64, bytes: Vec<u8>, last: bool },
+}
+
+/// A file read by a `File` component, along with its contents.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoadedFile {
+    /// The file which was read.
+    pub file: SysFile,
+    /// The contents of the file, or of a chunk of the file.
+    pub content: FileContent,
+}
+
+/// An error reading a file selected in a `File` component.
+#[derive(Clone, Debug, Display, PartialEq)]
+#[display(fmt = "Failed to read \"{}\": {}", "file.name()", message)]
+pub struct FileLoadError {
+    /// The file which could not be read.
+    pub file: SysFile,
+    /// The reason the file could not be read.
+    pub message: String,
+}
+
+impl std::error::Error for FileLoadError {}
+
+/// Start reading the given file, or the chunk of its bytes at the given offset when a chunk size
+/// is given, passing the contents to the given callback. The read is cancelled once the returned
+/// reader is dropped.
+pub(crate) fn read_file(
+    file: &SysFile, read_as: ReadAs, chunk_size: Option<u64>, offset: u64, callback: impl FnOnce(Result<FileContent, String>) + 'static,
+) -> FileReader {
+    let blob = Blob::from(file.clone());
+    match (read_as, chunk_size) {
+        (ReadAs::Text, _) => callbacks::read_as_text(&blob, move |result| {
+            callback(result.map(FileContent::Text).map_err(|err| err.to_string()))
+        }),
+        (ReadAs::DataUrl, _) => callbacks::read_as_data_url(&blob, move |result| {
+            callback(result.map(FileContent::DataUrl).map_err(|err| err.to_string()))
+        }),
+        (ReadAs::Bytes, None) => callbacks::read_as_bytes(&blob, move |result| {
+            callback(result.map(FileContent::Bytes).map_err(|err| err.to_string()))
+        }),
+        (ReadAs::Bytes, Some(chunk_size)) => {
+            let size = blob.size();
+            let end = offset.saturating_add(chunk_size.max(1)).min(size);
+            callbacks::read_as_bytes(&blob.slice(offset, end), move |result| {
+                let content = result.map(|bytes| FileContent::Chunk { offset, bytes, last: end >= size });
+                callback(content.map_err(|err| err.to_string()))
+            })
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use yew::prelude::*;
+use yewtil::NeqAssign;
+
+use super::{AnyField, Form, FormLayout, FormRenderer, YbcForm};
+use crate::{Button, Control, Field};
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct AutoFormProps<M: YbcForm> {
+    /// The initial model of this form.
+    pub model: M,
+    /// The callback to be used for propagating changes to the model.
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<M>,
+    /// The callback to be used for submitting the model, once all of the fields are valid.
+    #[prop_or_else(Callback::noop)]
+    pub onsubmit: Callback<M>,
+    /// The layout of the fields, overriding the layout of the model.
+    #[prop_or_default]
+    pub layout: Option<FormLayout>,
+    /// The text of the submit button.
+    #[prop_or_else(|| "Submit".into())]
+    pub submit_label: String,
+    #[prop_or_default]
+    pub classes: Option<Classes>,
+}
+
+/// A `Form` rendering all of the fields of a model implementing `YbcForm`, followed by a submit
+/// button.
+pub struct AutoForm<M: YbcForm> {
+    props: AutoFormProps<M>,
+    /// The fields of the model, built once per `layout` & `submit_label`.
+    fields: Vec<AnyField<M>>,
+    /// The renderer of the form's contents, built once per `layout` & `submit_label`.
+    view: FormRenderer<M>,
+}
+
+impl<M: YbcForm> Component for AutoForm<M> {
+    type Message = ();
+    type Properties = AutoFormProps<M>;
+
+    fn create(props: Self::Properties, _: ComponentLink<Self>) -> Self {
+        let view = Self::renderer(&props);
+        Self { props, fields: M::form_fields(), view }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props.layout != self.props.layout || props.submit_label != self.props.submit_label {
+            self.fields = M::form_fields();
+            self.view = Self::renderer(&props);
+        }
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <Form<M>
+                model=self.props.model.clone()
+                fields=self.fields.clone()
+                view=self.view.clone()
+                onchange=self.props.onchange.clone()
+                onsubmit=self.props.onsubmit.clone()
+                classes=self.props.classes.clone()
+                />
+        }
+    }
+}
+
+impl<M: YbcForm> AutoForm<M> {
+    /// Build the renderer of the fields & submit button, for the given props.
+    fn renderer(props: &AutoFormProps<M>) -> FormRenderer<M> {
+        let layout = props.layout.unwrap_or_else(M::layout);
+        let submit_label = props.submit_label.clone();
+        FormRenderer::new(move |ctx| {
+            let horizontal = layout == FormLayout::Horizontal;
+            let label = horizontal.then(String::new);
+            html! {
+                <>
+                    {M::view_fields(ctx, layout)}
+                    <Field horizontal=horizontal label=label>
+                        <Control>
+                            <Button classes=classes!("is-primary")>{submit_label.clone()}</Button>
+                        </Control>
+                    </Field>
+                </>
+            }
+        })
+    }
+}
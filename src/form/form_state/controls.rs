@@ -0,0 +1,391 @@
+use std::fmt;
+use std::str::FromStr;
+
+use yew::prelude::*;
+
+use super::{field_ids, FormContext, FormField};
+use crate::{Checkbox, Control, Field, Input, InputType, MultiSelect, Radio, Select, TextArea, TypedInput};
+
+/// The layout of the fields of a form.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FormLayout {
+    /// Labels above their controls.
+    #[default]
+    Vertical,
+    /// Labels beside their controls, as for the `horizontal` prop of `Field`.
+    Horizontal,
+}
+
+/// The options for rendering a field of a form via `FormContext::view_control`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FieldOptions {
+    /// The label of the field.
+    pub label: String,
+    /// The help message of the field, displayed while the field has no error.
+    pub help: Option<String>,
+    /// The placeholder of text & numeric controls.
+    pub placeholder: Option<String>,
+    /// The input type of text controls.
+    pub input_type: Option<InputType>,
+    /// Render text controls as a `TextArea`.
+    pub textarea: bool,
+    /// Render the options of a `FormOptions` value as `Radio` buttons instead of a `Select`.
+    pub radio: bool,
+    /// The `(value, label)` options of text values, which are then rendered as a `Select`, or of
+    /// lists of text values, rendered as a `MultiSelect`.
+    pub choices: Option<Vec<(String, String)>>,
+    /// The layout of the field.
+    pub layout: FormLayout,
+    /// The `id` of the control, which defaults to the name of the field.
+    pub id: Option<String>,
+}
+
+/// A type of value which can be edited by a form control.
+///
+/// This is implemented for `String` (an `Input`, `TextArea` or `Select`), `bool` (a `Checkbox`),
+/// numbers & optional numbers (a `TypedInput`), `Vec<String>` (a `MultiSelect`), and types
+/// implementing `FormOptions` (a `Select` or `Radio` buttons) along with lists of them (a
+/// `MultiSelect`).
+pub trait FormControl: Clone + PartialEq + Sized + 'static {
+    /// Whether the control displays the label of its field itself, such as a `Checkbox`.
+    const LABELS_ITSELF: bool = false;
+
+    /// Render the control for the given field of a form, within a `Control`.
+    fn view_control<M: Clone + PartialEq + 'static>(ctx: &FormContext<'_, M>, field: &FormField<M, Self>, options: &FieldOptions) -> Html;
+}
+
+/// A type with a fixed set of options, such as an enum of unit variants, for selection via a
+/// `Select` or `Radio` buttons.
+///
+/// This can be derived for enums of unit variants with `#[derive(FormOptions)]` when the `derive`
+/// feature is enabled.
+pub trait FormOptions: Clone + PartialEq + Sized + 'static {
+    /// The options, as pairs of the option & its label, in display order.
+    fn options() -> Vec<(Self, String)>;
+
+    /// The form value of this option, which is unique among the options.
+    fn value(&self) -> String;
+}
+
+/// A type of model which renders its own form, as derived via `#[derive(YbcForm)]` when the
+/// `derive` feature is enabled.
+pub trait YbcForm: Clone + PartialEq + Sized + 'static {
+    /// The layout of the form, unless otherwise given.
+    fn layout() -> FormLayout {
+        FormLayout::Vertical
+    }
+
+    /// The fields of the model, for the `fields` prop of a `Form`.
+    fn form_fields() -> Vec<super::AnyField<Self>>;
+
+    /// Render the fields of the model in the given layout.
+    fn view_fields(ctx: &FormContext<'_, Self>, layout: FormLayout) -> Html;
+}
+
+impl<'a, M: Clone + PartialEq + 'static> FormContext<'a, M> {
+    /// Render a `Field` with the control for the given field, displaying the field's error or
+    /// otherwise its help message.
+    pub fn view_control<V: FormControl>(&self, field: &FormField<M, V>, options: &FieldOptions) -> Html {
+        let error = self.error(field).map(ToString::to_string);
+        let has_error = error.is_some();
+        let help = error.or_else(|| options.help.clone());
+        let control = V::view_control(self, field, options);
+        let (id, _) = control_ids(self, field, options);
+        match options.layout {
+            FormLayout::Vertical => {
+                let label = (!V::LABELS_ITSELF).then(|| options.label.clone());
+                html! {
+                    <Field label=label control_id=id help=help help_has_error=has_error>
+                        {control}
+                    </Field>
+                }
+            }
+            FormLayout::Horizontal => {
+                let label = if V::LABELS_ITSELF { String::new() } else { options.label.clone() };
+                html! {
+                    <Field horizontal=true label=label control_id=id.clone()>
+                        <Field control_id=id help=help help_has_error=has_error>
+                            {control}
+                        </Field>
+                    </Field>
+                }
+            }
+        }
+    }
+}
+
+impl FormControl for String {
+    fn view_control<M: Clone + PartialEq + 'static>(ctx: &FormContext<'_, M>, field: &FormField<M, Self>, options: &FieldOptions) -> Html {
+        let name = field.name().to_string();
+        let value = ctx.value(field);
+        let (id, describedby) = control_ids(ctx, field, options);
+        if let Some(choices) = &options.choices {
+            let choices = choices
+                .iter()
+                .map(|(option, label)| view_option(option, label, option == &value))
+                .collect::<Html>();
+            return html! {
+                <Control>
+                    <Select
+                        name=name
+                        value=value
+                        update=ctx.input(field)
+                        classes=ctx.classes(field)
+                        id=id
+                        aria_describedby=describedby
+                        onblur=ctx.blur(field)
+                    >
+                        {choices}
+                    </Select>
+                </Control>
+            };
+        }
+        let placeholder = options.placeholder.clone().unwrap_or_default();
+        if options.textarea {
+            return html! {
+                <Control>
+                    <TextArea
+                        name=name
+                        value=value
+                        update=ctx.input(field)
+                        classes=ctx.classes(field)
+                        placeholder=placeholder
+                        id=id
+                        aria_describedby=describedby
+                        onblur=ctx.blur(field)
+                        />
+                </Control>
+            };
+        }
+        html! {
+            <Control>
+                <Input
+                    name=name
+                    value=value
+                    update=ctx.input(field)
+                    classes=ctx.classes(field)
+                    r#type=options.input_type.clone().unwrap_or(InputType::Text)
+                    placeholder=placeholder
+                    id=id
+                    aria_describedby=describedby
+                    onblur=ctx.blur(field)
+                    />
+            </Control>
+        }
+    }
+}
+
+impl FormControl for bool {
+    const LABELS_ITSELF: bool = true;
+
+    fn view_control<M: Clone + PartialEq + 'static>(ctx: &FormContext<'_, M>, field: &FormField<M, Self>, options: &FieldOptions) -> Html {
+        let (id, describedby) = control_ids(ctx, field, options);
+        html! {
+            <Control>
+                <Checkbox
+                    name=field.name().to_string()
+                    checked=ctx.value(field)
+                    update=ctx.input(field)
+                    classes=ctx.classes(field)
+                    id=id
+                    aria_describedby=describedby
+                    onblur=ctx.blur(field)
+                >
+                    {" "}{options.label.clone()}
+                </Checkbox>
+            </Control>
+        }
+    }
+}
+
+impl FormControl for Vec<String> {
+    fn view_control<M: Clone + PartialEq + 'static>(ctx: &FormContext<'_, M>, field: &FormField<M, Self>, options: &FieldOptions) -> Html {
+        let value = ctx.value(field);
+        let (id, describedby) = control_ids(ctx, field, options);
+        let choices = options
+            .choices
+            .iter()
+            .flatten()
+            .map(|(option, label)| view_option(option, label, value.contains(option)))
+            .collect::<Html>();
+        html! {
+            <Control>
+                <MultiSelect
+                    name=field.name().to_string()
+                    value=value
+                    update=ctx.input(field)
+                    classes=ctx.classes(field)
+                    id=id
+                    aria_describedby=describedby
+                    onblur=ctx.blur(field)
+                >
+                    {choices}
+                </MultiSelect>
+            </Control>
+        }
+    }
+}
+
+impl<T: FormOptions> FormControl for T {
+    fn view_control<M: Clone + PartialEq + 'static>(ctx: &FormContext<'_, M>, field: &FormField<M, Self>, options: &FieldOptions) -> Html {
+        let name = field.name().to_string();
+        let value = ctx.value(field).value();
+        let (id, describedby) = control_ids(ctx, field, options);
+        let update = ctx.input(field);
+        let update = Callback::from(move |value: String| {
+            if let Some(option) = find_option::<T>(&value) {
+                update.emit(option);
+            }
+        });
+        if options.radio {
+            let radios = T::options()
+                .into_iter()
+                .enumerate()
+                .map(|(index, (option, label))| {
+                    // The first button is the target of the label of the field.
+                    html! {
+                        <Radio
+                            name=name.clone()
+                            value=option.value()
+                            checked_value=Some(value.clone())
+                            update=update.clone()
+                            id=(index == 0).then(|| id.clone())
+                            aria_describedby=describedby.clone()
+                            onblur=ctx.blur(field)
+                        >
+                            {" "}{label}
+                        </Radio>
+                    }
+                })
+                .collect::<Html>();
+            return html! {<Control>{radios}</Control>};
+        }
+        let choices = T::options()
+            .into_iter()
+            .map(|(option, label)| view_option(&option.value(), &label, option.value() == value))
+            .collect::<Html>();
+        html! {
+            <Control>
+                <Select
+                    name=name
+                    value=value.clone()
+                    update=update
+                    classes=ctx.classes(field)
+                    id=id
+                    aria_describedby=describedby
+                    onblur=ctx.blur(field)
+                >
+                    {choices}
+                </Select>
+            </Control>
+        }
+    }
+}
+
+impl<T: FormOptions> FormControl for Vec<T> {
+    fn view_control<M: Clone + PartialEq + 'static>(ctx: &FormContext<'_, M>, field: &FormField<M, Self>, options: &FieldOptions) -> Html {
+        let value = ctx.value(field).iter().map(FormOptions::value).collect::<Vec<_>>();
+        let (id, describedby) = control_ids(ctx, field, options);
+        let update = ctx.input(field);
+        let update = Callback::from(move |values: Vec<String>| {
+            update.emit(values.iter().filter_map(|value| find_option::<T>(value)).collect());
+        });
+        let choices = T::options()
+            .into_iter()
+            .map(|(option, label)| {
+                let option = option.value();
+                let selected = value.contains(&option);
+                view_option(&option, &label, selected)
+            })
+            .collect::<Html>();
+        html! {
+            <Control>
+                <MultiSelect
+                    name=field.name().to_string()
+                    value=value
+                    update=update
+                    classes=ctx.classes(field)
+                    id=id
+                    aria_describedby=describedby
+                    onblur=ctx.blur(field)
+                >
+                    {choices}
+                </MultiSelect>
+            </Control>
+        }
+    }
+}
+
+macro_rules! impl_number_control {
+    ($($ty:ty),*) => {
+        $(
+            impl FormControl for $ty {
+                fn view_control<M: Clone + PartialEq + 'static>(ctx: &FormContext<'_, M>, field: &FormField<M, Self>, options: &FieldOptions) -> Html {
+                    // Text which does not parse to a number leaves the model unchanged.
+                    let update = ctx.input(field);
+                    let update = Callback::from(move |value: Option<$ty>| {
+                        if let Some(value) = value {
+                            update.emit(value);
+                        }
+                    });
+                    view_number(ctx, field, Some(ctx.value(field)), update, options)
+                }
+            }
+
+            impl FormControl for Option<$ty> {
+                fn view_control<M: Clone + PartialEq + 'static>(ctx: &FormContext<'_, M>, field: &FormField<M, Self>, options: &FieldOptions) -> Html {
+                    view_number(ctx, field, ctx.value(field), ctx.input(field), options)
+                }
+            }
+        )*
+    };
+}
+
+impl_number_control!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// Render a `TypedInput` for the given numeric field.
+fn view_number<M, V, T>(
+    ctx: &FormContext<'_, M>, field: &FormField<M, V>, value: Option<T>, update: Callback<Option<T>>, options: &FieldOptions,
+) -> Html
+where
+    M: Clone + PartialEq + 'static,
+    T: FromStr + fmt::Display + PartialOrd + Clone + 'static,
+    T::Err: fmt::Display,
+{
+    let (id, describedby) = control_ids(ctx, field, options);
+    html! {
+        <Control>
+            <TypedInput<T>
+                name=field.name().to_string()
+                value=value
+                update=update
+                classes=ctx.classes(field)
+                placeholder=options.placeholder.clone().unwrap_or_default()
+                id=id
+                aria_describedby=describedby
+                onblur=ctx.blur(field)
+                />
+        </Control>
+    }
+}
+
+/// The `id` of the control for the given field, and the `id` of its help message while one is
+/// displayed.
+fn control_ids<M, V>(ctx: &FormContext<'_, M>, field: &FormField<M, V>, options: &FieldOptions) -> (String, Option<String>)
+where
+    M: Clone + PartialEq + 'static,
+{
+    let has_help = ctx.error(field).is_some() || options.help.is_some();
+    field_ids(field.name(), options.id.as_deref(), has_help)
+}
+
+fn view_option(value: &str, label: &str, selected: bool) -> Html {
+    html! {<option value=value.to_string() selected=selected>{label}</option>}
+}
+
+fn find_option<T: FormOptions>(value: &str) -> Option<T> {
+    T::options()
+        .into_iter()
+        .map(|(option, _)| option)
+        .find(|option| option.value() == value)
+}
@@ -0,0 +1,221 @@
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use crate::form::validation::{RuleValue, Rules};
+
+/// The future of an asynchronous validation of a form field, resolving to the validation error
+/// of the field's value, if any.
+pub type ValidationFuture = Pin<Box<dyn Future<Output = Result<(), String>>>>;
+
+type GetFn<M, V> = Rc<dyn Fn(&M) -> V>;
+type SetFn<M, V> = Rc<dyn Fn(&mut M, V)>;
+type ValidateFn<M, V> = Rc<dyn Fn(&V, &M) -> Result<(), String>>;
+type ValidateAsyncFn<V> = Rc<dyn Fn(V) -> ValidationFuture>;
+
+/// A typed field of the model of a `Form`.
+///
+/// Fields are identified by their name, and access their value within the model via the given
+/// getter & setter, so that form controls can be bound to any part of the model.
+///
+/// ```
+/// use ybc::FormField;
+///
+/// #[derive(Clone, Default, PartialEq)]
+/// struct Signup {
+///     email: String,
+///     age: Option<u32>,
+/// }
+///
+/// let email = FormField::new("email", |m: &Signup| m.email.clone(), |m, v| m.email = v)
+///     .validate(|email, _| if email.contains('@') { Ok(()) } else { Err("Enter an email address".into()) });
+/// let age = FormField::new("age", |m: &Signup| m.age, |m, v| m.age = v)
+///     .validate(|age, _| age.map(|_| ()).ok_or_else(|| "Enter your age".to_string()));
+///
+/// let model = Signup { email: "ada@example.com".into(), age: None };
+/// assert_eq!(email.check(&model), Ok(()));
+/// assert_eq!(age.check(&model), Err("Enter your age".into()));
+/// ```
+pub struct FormField<M, V> {
+    name: Rc<str>,
+    get: GetFn<M, V>,
+    set: SetFn<M, V>,
+    validators: Vec<ValidateFn<M, V>>,
+    validate_async: Option<ValidateAsyncFn<V>>,
+}
+
+impl<M: 'static, V: Clone + PartialEq + 'static> FormField<M, V> {
+    /// Create a new field with the given name, from the given functions getting & setting the
+    /// value of the field within the model.
+    pub fn new(name: &str, get: impl Fn(&M) -> V + 'static, set: impl Fn(&mut M, V) + 'static) -> Self {
+        Self {
+            name: name.into(),
+            get: Rc::new(get),
+            set: Rc::new(set),
+            validators: vec![],
+            validate_async: None,
+        }
+    }
+
+    /// Add a validator to this field, given the field's value & the whole model.
+    ///
+    /// Validators are applied in the order in which they are added, and the first error is
+    /// displayed.
+    pub fn validate(mut self, validator: impl Fn(&V, &M) -> Result<(), String> + 'static) -> Self {
+        self.validators.push(Rc::new(validator));
+        self
+    }
+
+    /// Validate this field with the given rules, applied to the plain-text value of the field.
+    pub fn rules(self, rules: Rules) -> Self
+    where
+        V: RuleValue,
+    {
+        self.validate(move |value, _| rules.check(&value.rule_value()))
+    }
+
+    /// Validate this field with the rules given by the model, such as for requiring the field to
+    /// be equal to another field via `Rules::equal_to`.
+    pub fn rules_with(self, rules: impl Fn(&M) -> Rules + 'static) -> Self
+    where
+        V: RuleValue,
+    {
+        self.validate(move |value, model| rules(model).check(&value.rule_value()))
+    }
+
+    /// Set the asynchronous validator of this field, such as a request checking that a username is
+    /// available.
+    ///
+    /// The asynchronous validator is applied once all other validators accept the value. Results
+    /// for values which have since changed are discarded.
+    pub fn validate_async<F>(mut self, validator: impl Fn(V) -> F + 'static) -> Self
+    where
+        F: Future<Output = Result<(), String>> + 'static,
+    {
+        self.validate_async = Some(Rc::new(move |value| Box::pin(validator(value))));
+        self
+    }
+
+    /// Get the value of this field from the given model.
+    pub fn get(&self, model: &M) -> V {
+        (self.get)(model)
+    }
+
+    /// Set the value of this field within the given model.
+    pub fn set(&self, model: &mut M, value: V) {
+        (self.set)(model, value)
+    }
+
+    /// Apply the validators of this field to its value within the given model.
+    pub fn check(&self, model: &M) -> Result<(), String> {
+        let value = self.get(model);
+        self.validators.iter().try_for_each(|validator| validator(&value, model))
+    }
+
+    /// Start the asynchronous validation of this field's value within the given model, if the
+    /// field has an asynchronous validator.
+    pub fn check_async(&self, model: &M) -> Option<ValidationFuture> {
+        self.validate_async.as_ref().map(|validator| validator(self.get(model)))
+    }
+}
+
+impl<M, V> FormField<M, V> {
+    /// The name of this field.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<M, V> Clone for FormField<M, V> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            get: self.get.clone(),
+            set: self.set.clone(),
+            validators: self.validators.clone(),
+            validate_async: self.validate_async.clone(),
+        }
+    }
+}
+
+/// Fields are equal when they share the same name & accessor functions.
+impl<M, V> PartialEq for FormField<M, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && Rc::ptr_eq(&self.get, &other.get) && Rc::ptr_eq(&self.set, &other.set)
+    }
+}
+
+impl<M, V> fmt::Debug for FormField<M, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FormField").field("name", &self.name).finish()
+    }
+}
+
+type CheckFn<M> = Rc<dyn Fn(&M) -> Result<(), String>>;
+type CheckAsyncFn<M> = Rc<dyn Fn(&M) -> Option<ValidationFuture>>;
+type ChangedFn<M> = Rc<dyn Fn(&M, &M) -> bool>;
+
+/// A field of the model of a `Form`, with the type of its value erased.
+///
+/// This is created from a `FormField` via `From`, for listing the fields of a form.
+pub struct AnyField<M> {
+    pub(crate) name: Rc<str>,
+    check: CheckFn<M>,
+    check_async: CheckAsyncFn<M>,
+    changed: ChangedFn<M>,
+}
+
+impl<M> AnyField<M> {
+    /// Apply the validators of this field to its value within the given model.
+    pub(crate) fn check(&self, model: &M) -> Result<(), String> {
+        (self.check)(model)
+    }
+
+    /// Start the asynchronous validation of this field's value within the given model.
+    pub(crate) fn check_async(&self, model: &M) -> Option<ValidationFuture> {
+        (self.check_async)(model)
+    }
+
+    /// Check if the value of this field differs between the given models.
+    pub(crate) fn changed(&self, a: &M, b: &M) -> bool {
+        (self.changed)(a, b)
+    }
+}
+
+impl<M: 'static, V: Clone + PartialEq + 'static> From<FormField<M, V>> for AnyField<M> {
+    fn from(field: FormField<M, V>) -> Self {
+        let field = Rc::new(field);
+        let (check, check_async, changed) = (field.clone(), field.clone(), field.clone());
+        Self {
+            name: field.name.clone(),
+            check: Rc::new(move |model| check.check(model)),
+            check_async: Rc::new(move |model| check_async.check_async(model)),
+            changed: Rc::new(move |a, b| changed.get(a) != changed.get(b)),
+        }
+    }
+}
+
+impl<M> Clone for AnyField<M> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            check: self.check.clone(),
+            check_async: self.check_async.clone(),
+            changed: self.changed.clone(),
+        }
+    }
+}
+
+/// Fields are equal when they share the same name & functions.
+impl<M> PartialEq for AnyField<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && Rc::ptr_eq(&self.check, &other.check)
+    }
+}
+
+impl<M> fmt::Debug for AnyField<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AnyField").field("name", &self.name).finish()
+    }
+}
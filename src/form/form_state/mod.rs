@@ -0,0 +1,525 @@
+#![allow(clippy::unnecessary_operation)]
+
+mod auto;
+mod controls;
+mod field;
+
+pub use auto::{AutoForm, AutoFormProps};
+pub use controls::{FieldOptions, FormControl, FormLayout, FormOptions, YbcForm};
+pub use field::{AnyField, FormField, ValidationFuture};
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::rc::Rc;
+
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+use crate::Field;
+
+/// A function rendering the contents of a `Form`, given the form's current state.
+pub struct FormRenderer<M: Clone + PartialEq + 'static>(RenderFn<M>);
+
+type RenderFn<M> = Rc<dyn Fn(&FormContext<'_, M>) -> Html>;
+
+impl<M: Clone + PartialEq + 'static> FormRenderer<M> {
+    /// Create a new form renderer from the given function.
+    pub fn new(render: impl Fn(&FormContext<'_, M>) -> Html + 'static) -> Self {
+        Self(Rc::new(render))
+    }
+}
+
+impl<M: Clone + PartialEq + 'static> Clone for FormRenderer<M> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// Form renderers are equal when they share the same function.
+impl<M: Clone + PartialEq + 'static> PartialEq for FormRenderer<M> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<M: Clone + PartialEq + 'static> fmt::Debug for FormRenderer<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FormRenderer").finish()
+    }
+}
+
+/// The message type used by the `Form` component.
+pub enum FormMsg<M> {
+    /// The value of a field was changed.
+    Set { name: Rc<str>, apply: Box<dyn FnOnce(&mut M)> },
+    /// A field lost focus.
+    Touch(Rc<str>),
+    /// The asynchronous validation of a field completed.
+    Validated {
+        name: Rc<str>,
+        generation: u64,
+        result: Result<(), String>,
+    },
+    /// Submit the form, once all of its fields are valid.
+    Submit,
+    /// Reset the form to its initial model.
+    Reset,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct FormProps<M: Clone + PartialEq + 'static> {
+    /// The initial model of this form.
+    ///
+    /// Changing this prop resets the form to the new model, while re-rendering the form with the
+    /// same model keeps its edits.
+    pub model: M,
+    /// The fields of the model, all of which are validated before the form is submitted.
+    pub fields: Vec<AnyField<M>>,
+    /// The function rendering the contents of this form.
+    pub view: FormRenderer<M>,
+    /// The callback to be used for propagating changes to the model.
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<M>,
+    /// The callback to be used for submitting the model, once all of the fields are valid.
+    #[prop_or_else(Callback::noop)]
+    pub onsubmit: Callback<M>,
+    #[prop_or_default]
+    pub classes: Option<Classes>,
+}
+
+/// A form, holding a model along with the validation state of its fields.
+///
+/// The contents of the form are rendered by the `view` function, given a `FormContext` for
+/// binding form controls to the typed `FormField`s of the model & displaying their errors. Each
+/// change of a field's value validates all fields, starting the asynchronous validation of the
+/// changed field if its other validators pass.
+///
+/// Errors are displayed once a field is touched, by losing focus, or dirty, by differing from the
+/// initial model, as well as once the form is submitted. Submitting the form, via a submit button
+/// or `FormContext::submit`, waits for any pending asynchronous validation & only propagates the
+/// model via `onsubmit` if all fields are valid.
+pub struct Form<M: Clone + PartialEq + 'static> {
+    link: ComponentLink<Self>,
+    props: FormProps<M>,
+    state: FormState<M>,
+    generation: u64,
+}
+
+/// The model of a `Form` along with the validation state of its fields.
+struct FormState<M> {
+    /// The current model.
+    model: M,
+    /// The model from which the form started, against which fields are dirty.
+    initial: M,
+    /// The names of the fields which lost focus.
+    touched: HashSet<Rc<str>>,
+    /// The synchronous validation errors of the fields, by name.
+    errors: HashMap<Rc<str>, String>,
+    /// The results of the asynchronous validation of the current values of the fields, by name.
+    async_results: HashMap<Rc<str>, Result<(), String>>,
+    /// The generation of the pending asynchronous validation of each field, by name.
+    pending: HashMap<Rc<str>, u64>,
+    /// Whether the form has been submitted.
+    submitted: bool,
+    /// Whether the form is waiting for asynchronous validation to complete before submitting.
+    submitting: bool,
+}
+
+impl<M: Clone + PartialEq + 'static> Component for Form<M> {
+    type Message = FormMsg<M>;
+    type Properties = FormProps<M>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let state = FormState::new(props.model.clone(), &props.fields);
+        Self { link, props, state, generation: 0 }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            FormMsg::Set { name, apply } => {
+                self.state.set(&name, apply, &self.props.fields);
+                if let Some(field) = self.field(&name).cloned() {
+                    self.validate_async(&field);
+                }
+                self.props.onchange.emit(self.state.model.clone());
+                self.finish_submit();
+                true
+            }
+            FormMsg::Touch(name) => self.state.touched.insert(name),
+            FormMsg::Validated { name, generation, result } => {
+                if self.state.pending.get(&name) != Some(&generation) {
+                    return false;
+                }
+                self.state.pending.remove(&name);
+                self.state.async_results.insert(name, result);
+                self.finish_submit();
+                true
+            }
+            FormMsg::Submit => {
+                self.state.submitted = true;
+                self.state.submitting = true;
+                self.state.validate_sync(&self.props.fields);
+                for field in self.props.fields.clone() {
+                    if !self.state.async_results.contains_key(&field.name) && !self.state.pending.contains_key(&field.name) {
+                        self.validate_async(&field);
+                    }
+                }
+                self.finish_submit();
+                true
+            }
+            FormMsg::Reset => {
+                self.state.reset(self.state.initial.clone(), &self.props.fields);
+                self.props.onchange.emit(self.state.model.clone());
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props == self.props {
+            return false;
+        }
+        self.state.change(&self.props, &props);
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let classes = self.props.classes.clone().unwrap_or_default();
+        let context = FormContext { form: self };
+        html! {
+            <form
+                class=classes
+                novalidate=true
+                onsubmit=self.link.callback(|event: FocusEvent| {
+                    event.prevent_default();
+                    FormMsg::Submit
+                })
+                onreset=self.link.callback(|event: Event| {
+                    event.prevent_default();
+                    FormMsg::Reset
+                })
+            >
+                {(self.props.view.0)(&context)}
+            </form>
+        }
+    }
+}
+
+impl<M: Clone + PartialEq + 'static> Form<M> {
+    /// Find the field with the given name.
+    fn field(&self, name: &str) -> Option<&AnyField<M>> {
+        self.props.fields.iter().find(|field| &*field.name == name)
+    }
+
+    /// Start the asynchronous validation of the given field, if it has an asynchronous validator
+    /// & its synchronous validators pass.
+    fn validate_async(&mut self, field: &AnyField<M>) {
+        if self.state.errors.contains_key(&field.name) {
+            return;
+        }
+        let future = match field.check_async(&self.state.model) {
+            Some(future) => future,
+            None => return,
+        };
+        self.generation += 1;
+        let generation = self.generation;
+        let name = field.name.clone();
+        self.state.pending.insert(name.clone(), generation);
+        let link = self.link.clone();
+        spawn_local(async move {
+            let result = future.await;
+            link.send_message(FormMsg::Validated { name, generation, result });
+        });
+    }
+
+    /// Submit the model if a submission is waiting & no validation is pending.
+    fn finish_submit(&mut self) {
+        if !self.state.submitting || !self.state.pending.is_empty() {
+            return;
+        }
+        self.state.submitting = false;
+        if self.state.is_valid() {
+            self.props.onsubmit.emit(self.state.model.clone());
+        }
+    }
+
+    /// Check if the error of the field with the given name is displayed.
+    fn shows_error(&self, name: &str) -> bool {
+        self.state.submitted || self.state.touched.contains(name) || self.is_dirty(name)
+    }
+
+    /// Check if the value of the field with the given name differs from the initial model.
+    fn is_dirty(&self, name: &str) -> bool {
+        self.field(name)
+            .map(|field| field.changed(&self.state.model, &self.state.initial))
+            .unwrap_or(false)
+    }
+}
+
+impl<M: Clone + PartialEq + 'static> FormState<M> {
+    /// Start from the given model.
+    fn new(model: M, fields: &[AnyField<M>]) -> Self {
+        let mut state = Self {
+            model: model.clone(),
+            initial: model,
+            touched: HashSet::new(),
+            errors: HashMap::new(),
+            async_results: HashMap::new(),
+            pending: HashMap::new(),
+            submitted: false,
+            submitting: false,
+        };
+        state.validate_sync(fields);
+        state
+    }
+
+    /// Apply a change of the value of the field with the given name.
+    fn set(&mut self, name: &str, apply: impl FnOnce(&mut M), fields: &[AnyField<M>]) {
+        apply(&mut self.model);
+        self.async_results.remove(name);
+        self.pending.remove(name);
+        self.validate_sync(fields);
+    }
+
+    /// Apply a change of the props of the form, resetting to the new model only if the `model`
+    /// prop itself changed, so that edits survive the parent re-rendering with the same model.
+    fn change(&mut self, old: &FormProps<M>, new: &FormProps<M>) {
+        if new.model != old.model {
+            self.reset(new.model.clone(), &new.fields);
+        } else {
+            self.validate_sync(&new.fields);
+        }
+    }
+
+    /// Reset the form to the given model.
+    fn reset(&mut self, model: M, fields: &[AnyField<M>]) {
+        *self = Self::new(model, fields);
+    }
+
+    /// Apply the synchronous validators of all fields.
+    fn validate_sync(&mut self, fields: &[AnyField<M>]) {
+        self.errors = fields
+            .iter()
+            .filter_map(|field| field.check(&self.model).err().map(|err| (field.name.clone(), err)))
+            .collect();
+    }
+
+    /// Check if all fields are valid, with no validation pending.
+    fn is_valid(&self) -> bool {
+        self.errors.is_empty() && self.pending.is_empty() && self.async_results.values().all(Result::is_ok)
+    }
+
+    /// The validation error of the field with the given name, whether or not it is displayed.
+    fn error(&self, name: &str) -> Option<&str> {
+        self.errors.get(name).map(String::as_str).or_else(|| {
+            self.async_results
+                .get(name)
+                .and_then(|result| result.as_ref().err())
+                .map(String::as_str)
+        })
+    }
+}
+
+/// The state of a `Form`, given to its `view` function for rendering its contents.
+pub struct FormContext<'a, M: Clone + PartialEq + 'static> {
+    form: &'a Form<M>,
+}
+
+impl<'a, M: Clone + PartialEq + 'static> FormContext<'a, M> {
+    /// The current model of the form.
+    pub fn model(&self) -> &M {
+        &self.form.state.model
+    }
+
+    /// The current value of the given field.
+    pub fn value<V: Clone + PartialEq + 'static>(&self, field: &FormField<M, V>) -> V {
+        field.get(&self.form.state.model)
+    }
+
+    /// A callback setting the value of the given field, for use as the `update` callback of a
+    /// form control.
+    pub fn input<V: Clone + PartialEq + 'static>(&self, field: &FormField<M, V>) -> Callback<V> {
+        let field = field.clone();
+        self.form.link.callback(move |value: V| {
+            let setter = field.clone();
+            FormMsg::Set {
+                name: field.name().into(),
+                apply: Box::new(move |model| setter.set(model, value)),
+            }
+        })
+    }
+
+    /// A callback marking the given field as touched, for use as the `onblur` callback of a form
+    /// control.
+    pub fn blur<V>(&self, field: &FormField<M, V>) -> Callback<FocusEvent> {
+        let name: Rc<str> = field.name().into();
+        self.form.link.callback(move |_| FormMsg::Touch(name.clone()))
+    }
+
+    /// A callback submitting the form, for controls other than submit buttons.
+    pub fn submit(&self) -> Callback<MouseEvent> {
+        self.form.link.callback(|_| FormMsg::Submit)
+    }
+
+    /// A callback resetting the form to its initial model, for controls other than reset buttons.
+    pub fn reset(&self) -> Callback<MouseEvent> {
+        self.form.link.callback(|_| FormMsg::Reset)
+    }
+
+    /// The displayed error of the given field, if any.
+    pub fn error<V>(&self, field: &FormField<M, V>) -> Option<&str> {
+        let name = field.name();
+        self.form.state.error(name).filter(|_| self.form.shows_error(name))
+    }
+
+    /// The displayed errors of all fields, as pairs of the field name & the error, in the order of
+    /// the form's fields.
+    pub fn errors(&self) -> Vec<(&str, &str)> {
+        self.form
+            .props
+            .fields
+            .iter()
+            .filter(|field| self.form.shows_error(&field.name))
+            .filter_map(|field| self.form.state.error(&field.name).map(|err| (&*field.name, err)))
+            .collect()
+    }
+
+    /// The Bulma classes reflecting the validity of the given field, for the `classes` prop of its
+    /// form control: `is-danger` while its error is displayed, or `is-success` once it is valid &
+    /// would otherwise display its error.
+    pub fn classes<V>(&self, field: &FormField<M, V>) -> Classes {
+        let name = field.name();
+        if !self.form.shows_error(name) || self.form.state.pending.contains_key(name) {
+            Classes::new()
+        } else if self.form.state.error(name).is_some() {
+            Classes::from("is-danger")
+        } else {
+            Classes::from("is-success")
+        }
+    }
+
+    /// Check if the given field differs from the initial model.
+    pub fn is_dirty<V>(&self, field: &FormField<M, V>) -> bool {
+        self.form.is_dirty(field.name())
+    }
+
+    /// Check if the given field has lost focus since the form started.
+    pub fn is_touched<V>(&self, field: &FormField<M, V>) -> bool {
+        self.form.state.touched.contains(field.name())
+    }
+
+    /// Check if the asynchronous validation of the given field is pending.
+    pub fn is_validating<V>(&self, field: &FormField<M, V>) -> bool {
+        self.form.state.pending.contains_key(field.name())
+    }
+
+    /// Check if all fields of the form are valid, with no validation pending.
+    pub fn is_valid(&self) -> bool {
+        self.form.state.is_valid()
+    }
+
+    /// Check if a submission is waiting for asynchronous validation to complete.
+    pub fn is_submitting(&self) -> bool {
+        self.form.state.submitting
+    }
+
+    /// The `id` of the error message of the given field while it has an error, for the
+    /// `aria_describedby` prop of its control.
+    pub fn help_id<V>(&self, field: &FormField<M, V>) -> Option<String> {
+        field_ids(field.name(), None, self.error(field).is_some()).1
+    }
+
+    /// Render a `Field` with the given label & control, displaying the error of the given field
+    /// as its help message.
+    ///
+    /// The label targets the control with the field's name as its `id`, and the `id` of the error
+    /// message is given by `help_id`.
+    pub fn field<V>(&self, field: &FormField<M, V>, label: &str, control: Html) -> Html {
+        let error = self.error(field).map(ToString::to_string);
+        let has_error = error.is_some();
+        let (control_id, _) = field_ids(field.name(), None, has_error);
+        html! {
+            <Field label=Some(label.to_string()) control_id=control_id help_has_error=has_error help=error>
+                {control}
+            </Field>
+        }
+    }
+}
+
+/// The `id` of the control for the field with the given name, which is the given `id` if any or
+/// else the field's name, and the `id` of its help message if one is displayed.
+fn field_ids(name: &str, id: Option<&str>, has_help: bool) -> (String, Option<String>) {
+    let id = id.unwrap_or(name).to_string();
+    let help_id = has_help.then(|| Field::help_id(&id));
+    (id, help_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct Signup {
+        email: String,
+    }
+
+    fn props(email: &str) -> FormProps<Signup> {
+        let field = FormField::new("email", |m: &Signup| m.email.clone(), |m, v| m.email = v).validate(|email, _| {
+            if email.contains('@') {
+                Ok(())
+            } else {
+                Err("Enter an email address".into())
+            }
+        });
+        FormProps {
+            model: Signup { email: email.into() },
+            fields: vec![field.into()],
+            view: FormRenderer::new(|_| html! {}),
+            onchange: Callback::noop(),
+            onsubmit: Callback::noop(),
+            classes: None,
+        }
+    }
+
+    fn edit(state: &mut FormState<Signup>, props: &FormProps<Signup>, email: &str) {
+        let email = email.to_string();
+        state.set("email", move |m: &mut Signup| m.email = email, &props.fields);
+        state.touched.insert("email".into());
+    }
+
+    #[test]
+    fn change_keeps_edits_for_same_model() {
+        let old = props("");
+        let mut state = FormState::new(old.model.clone(), &old.fields);
+        edit(&mut state, &old, "ada");
+
+        // The parent re-renders with the same model, but e.g. a new view function.
+        let new = props("");
+        state.change(&old, &new);
+        assert_eq!(state.model.email, "ada");
+        assert!(state.touched.contains("email"));
+        assert_eq!(state.error("email"), Some("Enter an email address"));
+    }
+
+    #[test]
+    fn change_resets_for_new_model() {
+        let old = props("");
+        let mut state = FormState::new(old.model.clone(), &old.fields);
+        edit(&mut state, &old, "ada");
+
+        let new = props("ada@example.com");
+        state.change(&old, &new);
+        assert_eq!(state.model, new.model);
+        assert_eq!(state.initial, new.model);
+        assert!(state.touched.is_empty());
+        assert!(state.is_valid());
+    }
+
+    #[test]
+    fn help_ids_follow_control_ids() {
+        assert_eq!(field_ids("email", None, true), ("email".into(), Some("email-help".into())));
+        assert_eq!(field_ids("email", Some("signup-email"), true).1, Some("signup-email-help".into()));
+        assert_eq!(field_ids("email", None, false).1, None);
+    }
+}
@@ -1,47 +1,78 @@
 #![allow(clippy::redundant_closure_call)]
 
+use std::fmt;
+use std::str::FromStr;
+
 use derive_more::Display;
 use yew::events::InputData;
 use yew::prelude::*;
 use yewtil::NeqAssign;
 
+use super::validation::Rules;
+use super::with_control_attrs;
 use crate::Size;
 
-#[derive(Clone, Debug, Properties, PartialEq)]
-pub struct InputProps {
-    /// The `name` attribute for this form element.
-    pub name: String,
-    /// The controlled value of this form element.
-    pub value: String,
-    /// The callback to be used for propagating changes to this element's value.
-    pub update: Callback<String>,
-
-    #[prop_or_default]
-    pub classes: Option<Classes>,
-    /// The input type of this component.
-    #[prop_or_else(|| InputType::Text)]
-    pub r#type: InputType,
-    /// The placeholder value for this component.
-    #[prop_or_default]
-    pub placeholder: String,
-    /// The size of this component.
-    #[prop_or_default]
-    pub size: Option<Size>,
-    /// Use rounded appearance.
-    #[prop_or_default]
-    pub rounded: bool,
-    /// Display a loading spinner within this component.
-    #[prop_or_default]
-    pub loading: bool,
-    /// Disable this component.
-    #[prop_or_default]
-    pub disabled: bool,
-    /// Make this component read-only.
-    #[prop_or_default]
-    pub readonly: bool,
-    /// Make this component static.
-    #[prop_or_default]
-    pub r#static: bool,
+control_props! {
+    #[derive(Clone, Debug, Properties, PartialEq)]
+    pub struct InputProps {
+        /// The `name` attribute for this form element.
+        pub name: String,
+        /// The controlled value of this form element.
+        pub value: String,
+        /// The callback to be used for propagating changes to this element's value.
+        pub update: Callback<String>,
+
+        #[prop_or_default]
+        pub classes: Option<Classes>,
+        /// The input type of this component.
+        #[prop_or_else(|| InputType::Text)]
+        pub r#type: InputType,
+        /// The placeholder value for this component.
+        #[prop_or_default]
+        pub placeholder: String,
+        /// The size of this component.
+        #[prop_or_default]
+        pub size: Option<Size>,
+        /// Use rounded appearance.
+        #[prop_or_default]
+        pub rounded: bool,
+        /// Display a loading spinner within this component.
+        #[prop_or_default]
+        pub loading: bool,
+        /// Disable this component.
+        #[prop_or_default]
+        pub disabled: bool,
+        /// Make this component read-only.
+        #[prop_or_default]
+        pub readonly: bool,
+        /// Make this component static.
+        #[prop_or_default]
+        pub r#static: bool,
+        /// Validation rules for the value of this component, adding the `is-success` or `is-danger`
+        /// class once the value has been changed.
+        #[prop_or_default]
+        pub rules: Option<Rules>,
+        /// The callback to be used for propagating the validation result of each new value, given
+        /// the `rules` of this component.
+        #[prop_or_else(Callback::noop)]
+        pub onvalidate: Callback<Result<(), String>>,
+        /// The `autocomplete` attribute for this form element, e.g. `email` or `off`.
+        #[prop_or_default]
+        pub autocomplete: Option<String>,
+        /// The maximum number of characters of the value of this component.
+        #[prop_or_default]
+        pub maxlength: Option<u32>,
+        /// The minimum number of characters of the value of this component.
+        #[prop_or_default]
+        pub minlength: Option<u32>,
+        /// A regular expression which the value of this component must match.
+        #[prop_or_default]
+        pub pattern: Option<String>,
+        /// The `inputmode` attribute for this form element, hinting at the virtual keyboard to
+        /// display.
+        #[prop_or_default]
+        pub inputmode: Option<String>,
+    }
 }
 
 /// A text input element.
@@ -54,6 +85,8 @@ pub struct InputProps {
 pub struct Input {
     props: InputProps,
     link: ComponentLink<Self>,
+    /// Whether the value has been changed by the user, after which its validity is displayed.
+    touched: bool,
 }
 
 impl Component for Input {
@@ -61,10 +94,14 @@ impl Component for Input {
     type Properties = InputProps;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Self { props, link }
+        Self { props, link, touched: false }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        if let Some(rules) = &self.props.rules {
+            self.props.onvalidate.emit(rules.check(&msg));
+        }
+        self.touched = true;
         self.props.update.emit(msg);
         false
     }
@@ -76,6 +113,9 @@ impl Component for Input {
     fn view(&self) -> Html {
         let mut classes = Classes::from("input");
         classes.push(&self.props.classes);
+        if let (true, Some(rules)) = (self.touched, &self.props.rules) {
+            classes.push(rules.class(&self.props.value));
+        }
         if let Some(size) = &self.props.size {
             classes.push(&size.to_string());
         }
@@ -88,7 +128,7 @@ impl Component for Input {
         if self.props.r#static {
             classes.push("is-static");
         }
-        html! {
+        let input = html! {
             <input
                 name=self.props.name.clone()
                 value=self.props.value.clone()
@@ -98,14 +138,21 @@ impl Component for Input {
                 placeholder=self.props.placeholder.clone()
                 disabled=self.props.disabled
                 readonly=self.props.readonly
+                autocomplete=self.props.autocomplete.clone()
+                maxlength=self.props.maxlength.map(|len| len.to_string())
+                minlength=self.props.minlength.map(|len| len.to_string())
+                pattern=self.props.pattern.clone()
+                inputmode=self.props.inputmode.clone()
                 />
-        }
+        };
+        with_control_attrs(input, control_attrs!(self.props))
     }
 }
 
-/// The 4 allowed types for an input component.
+/// The allowed types for an input component.
 ///
 /// https://bulma.io/documentation/form/input/
+/// https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#input_types
 #[derive(Clone, Debug, Display, PartialEq)]
 pub enum InputType {
     #[display(fmt = "text")]
@@ -116,4 +163,227 @@ pub enum InputType {
     Email,
     #[display(fmt = "tel")]
     Tel,
+    #[display(fmt = "number")]
+    Number,
+    #[display(fmt = "search")]
+    Search,
+    #[display(fmt = "url")]
+    Url,
+    #[display(fmt = "date")]
+    Date,
+    #[display(fmt = "time")]
+    Time,
+    #[display(fmt = "datetime-local")]
+    DateTimeLocal,
+    #[display(fmt = "month")]
+    Month,
+    #[display(fmt = "week")]
+    Week,
+    #[display(fmt = "color")]
+    Color,
+    #[display(fmt = "range")]
+    Range,
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+/// The error of parsing the text of a `TypedInput`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The text of the input could not be parsed; holding the message of the parse error.
+    Invalid(String),
+    /// The value is less than the `min` of the input.
+    BelowMin,
+    /// The value is greater than the `max` of the input.
+    AboveMax,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "a value is required"),
+            ParseError::Invalid(msg) => write!(f, "{}", msg),
+            ParseError::BelowMin => write!(f, "the value is below the minimum"),
+            ParseError::AboveMax => write!(f, "the value is above the maximum"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+control_props! {
+    #[derive(Clone, Debug, Properties, PartialEq)]
+    pub struct TypedInputProps<T: FromStr + fmt::Display + PartialOrd + Clone + 'static> {
+        /// The `name` attribute for this form element.
+        pub name: String,
+        /// The controlled value of this form element; `None` while the input is empty or invalid.
+        pub value: Option<T>,
+        /// The callback to be used for propagating changes to this element's value; `None` while
+        /// the input is empty or invalid.
+        #[prop_or_else(Callback::noop)]
+        pub update: Callback<Option<T>>,
+        /// The callback to be used for propagating the result of parsing this element's text, as it
+        /// changes.
+        #[prop_or_else(Callback::noop)]
+        pub onparse: Callback<Result<T, ParseError>>,
+
+        #[prop_or_default]
+        pub classes: Option<Classes>,
+        /// The input type of this component.
+        #[prop_or_else(|| InputType::Number)]
+        pub r#type: InputType,
+        /// The minimum value of this component.
+        #[prop_or_default]
+        pub min: Option<T>,
+        /// The maximum value of this component.
+        #[prop_or_default]
+        pub max: Option<T>,
+        /// The granularity of the values of this component.
+        #[prop_or_default]
+        pub step: Option<T>,
+        /// The placeholder value for this component.
+        #[prop_or_default]
+        pub placeholder: String,
+        /// The size of this component.
+        #[prop_or_default]
+        pub size: Option<Size>,
+        /// Use rounded appearance.
+        #[prop_or_default]
+        pub rounded: bool,
+        /// Display a loading spinner within this component.
+        #[prop_or_default]
+        pub loading: bool,
+        /// Disable this component.
+        #[prop_or_default]
+        pub disabled: bool,
+        /// Make this component read-only.
+        #[prop_or_default]
+        pub readonly: bool,
+        /// The `autocomplete` attribute for this form element, e.g. `email` or `off`.
+        #[prop_or_default]
+        pub autocomplete: Option<String>,
+        /// The maximum number of characters of the value of this component.
+        #[prop_or_default]
+        pub maxlength: Option<u32>,
+        /// The minimum number of characters of the value of this component.
+        #[prop_or_default]
+        pub minlength: Option<u32>,
+        /// A regular expression which the value of this component must match.
+        #[prop_or_default]
+        pub pattern: Option<String>,
+        /// The `inputmode` attribute for this form element, hinting at the virtual keyboard to
+        /// display.
+        #[prop_or_default]
+        pub inputmode: Option<String>,
+    }
+}
+
+/// An input element for values of type `T`, parsed via `FromStr` & displayed via `Display`.
+///
+/// [https://bulma.io/documentation/form/input/](https://bulma.io/documentation/form/input/)
+///
+/// The text of the input is parsed as it changes, propagating the value via the `update` callback
+/// & the full result, including why the text is not valid, via the `onparse` callback. Values
+/// outside of the `min` & `max` bounds are not valid.
+///
+/// The text typed by the user is retained while it parses to the controlled value, so that inputs
+/// such as `1.` or `1.50` are not reformatted as they are typed.
+pub struct TypedInput<T>
+where
+    T: FromStr + fmt::Display + PartialOrd + Clone + 'static,
+    T::Err: fmt::Display,
+{
+    props: TypedInputProps<T>,
+    link: ComponentLink<Self>,
+    /// The current text of the input.
+    text: String,
+}
+
+impl<T> Component for TypedInput<T>
+where
+    T: FromStr + fmt::Display + PartialOrd + Clone + 'static,
+    T::Err: fmt::Display,
+{
+    type Message = String;
+    type Properties = TypedInputProps<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let text = props.value.as_ref().map(ToString::to_string).unwrap_or_default();
+        Self { props, link, text }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        self.text = msg;
+        let result = self.parse();
+        self.props.update.emit(result.clone().ok());
+        self.props.onparse.emit(result);
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props.value != self.parse().ok() {
+            self.text = props.value.as_ref().map(ToString::to_string).unwrap_or_default();
+        }
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = Classes::from("input");
+        classes.push(&self.props.classes);
+        if let Some(size) = &self.props.size {
+            classes.push(size.to_string());
+        }
+        if self.props.rounded {
+            classes.push("is-rounded");
+        }
+        if self.props.loading {
+            classes.push("is-loading");
+        }
+        let input = html! {
+            <input
+                name=self.props.name.clone()
+                value=self.text.clone()
+                oninput=self.link.callback(|input: InputData| input.value)
+                class=classes
+                type=self.props.r#type.to_string()
+                min=self.props.min.as_ref().map(ToString::to_string)
+                max=self.props.max.as_ref().map(ToString::to_string)
+                step=self.props.step.as_ref().map(ToString::to_string)
+                placeholder=self.props.placeholder.clone()
+                disabled=self.props.disabled
+                readonly=self.props.readonly
+                autocomplete=self.props.autocomplete.clone()
+                maxlength=self.props.maxlength.map(|len| len.to_string())
+                minlength=self.props.minlength.map(|len| len.to_string())
+                pattern=self.props.pattern.clone()
+                inputmode=self.props.inputmode.clone()
+                />
+        };
+        with_control_attrs(input, control_attrs!(self.props))
+    }
+}
+
+impl<T> TypedInput<T>
+where
+    T: FromStr + fmt::Display + PartialOrd + Clone + 'static,
+    T::Err: fmt::Display,
+{
+    /// Parse the current text of the input, checking the value against the bounds.
+    fn parse(&self) -> Result<T, ParseError> {
+        let text = self.text.trim();
+        if text.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let value = text.parse::<T>().map_err(|err| ParseError::Invalid(err.to_string()))?;
+        if self.props.min.as_ref().map(|min| value < *min).unwrap_or(false) {
+            return Err(ParseError::BelowMin);
+        }
+        if self.props.max.as_ref().map(|max| value > *max).unwrap_or(false) {
+            return Err(ParseError::AboveMax);
+        }
+        Ok(value)
+    }
 }
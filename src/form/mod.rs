@@ -1,8 +1,126 @@
+use std::rc::Rc;
+
+use yew::html::{onblur, onfocus, onkeydown};
+use yew::virtual_dom::VNode;
+use yew::{Callback, FocusEvent, Html, KeyboardEvent};
+
+/// Declare the props of a form control, appending the attributes & callbacks common to all form
+/// controls, which are applied to the form element via `with_control_attrs`.
+macro_rules! control_props {
+    // Emit the struct once only its body remains.
+    (@munch [$($header:tt)*] { $($fields:tt)* }) => {
+        $($header)* {
+            $($fields)*
+            /// The `id` attribute for this form element.
+            #[prop_or_default]
+            pub id: Option<String>,
+            /// Require a value for this component.
+            #[prop_or_default]
+            pub required: bool,
+            /// Focus this component when the page loads.
+            #[prop_or_default]
+            pub autofocus: bool,
+            /// The `aria-label` attribute for this form element.
+            #[prop_or_default]
+            pub aria_label: Option<String>,
+            /// The `aria-describedby` attribute for this form element.
+            #[prop_or_default]
+            pub aria_describedby: Option<String>,
+            /// Additional attributes for this form element, as pairs of the attribute name & value.
+            #[prop_or_default]
+            pub attrs: Vec<(&'static str, String)>,
+            /// The callback to be used for key presses within this component.
+            #[prop_or_default]
+            pub onkeydown: Callback<KeyboardEvent>,
+            /// The callback to be used when this component gains focus.
+            #[prop_or_default]
+            pub onfocus: Callback<FocusEvent>,
+            /// The callback to be used when this component loses focus.
+            #[prop_or_default]
+            pub onblur: Callback<FocusEvent>,
+        }
+    };
+    (@munch [$($header:tt)*] $next:tt $($rest:tt)*) => {
+        control_props!(@munch [$($header)* $next] $($rest)*);
+    };
+    ($($item:tt)*) => {
+        control_props!(@munch [] $($item)*);
+    };
+}
+
+/// Borrow the common attributes & callbacks of the given form control props, as declared via
+/// `control_props!`.
+macro_rules! control_attrs {
+    ($props:expr) => {
+        $crate::form::ControlAttrs {
+            id: &$props.id,
+            required: $props.required,
+            autofocus: $props.autofocus,
+            aria_label: &$props.aria_label,
+            aria_describedby: &$props.aria_describedby,
+            attrs: &$props.attrs,
+            onkeydown: &$props.onkeydown,
+            onfocus: &$props.onfocus,
+            onblur: &$props.onblur,
+        }
+    };
+}
+
+pub mod autocomplete;
 pub mod checkbox;
 pub mod control;
 pub mod field;
 pub mod file;
+pub mod file_drop_zone;
+pub mod file_reader;
+pub mod form_state;
 pub mod input;
+mod pattern;
 pub mod radio;
 pub mod select;
+pub mod tags_input;
 pub mod textarea;
+pub mod validation;
+
+/// The attributes & callbacks common to all form controls, borrowed from their props.
+pub(crate) struct ControlAttrs<'a> {
+    pub id: &'a Option<String>,
+    pub required: bool,
+    pub autofocus: bool,
+    pub aria_label: &'a Option<String>,
+    pub aria_describedby: &'a Option<String>,
+    pub attrs: &'a [(&'static str, String)],
+    pub onkeydown: &'a Callback<KeyboardEvent>,
+    pub onfocus: &'a Callback<FocusEvent>,
+    pub onblur: &'a Callback<FocusEvent>,
+}
+
+/// Add the common attributes & callbacks of a form control to the given form element. The extra
+/// `attrs` override any attributes of the same name which the element already has.
+pub(crate) fn with_control_attrs(mut node: Html, control: ControlAttrs<'_>) -> Html {
+    if let VNode::VTag(tag) = &mut node {
+        let optional = [
+            ("id", control.id),
+            ("aria-label", control.aria_label),
+            ("aria-describedby", control.aria_describedby),
+        ];
+        for (key, value) in optional.iter() {
+            if let Some(value) = value {
+                tag.add_attribute(key, value.clone());
+            }
+        }
+        if control.required {
+            tag.add_attribute("required", "required");
+        }
+        if control.autofocus {
+            tag.add_attribute("autofocus", "autofocus");
+        }
+        tag.add_listener(Rc::new(onkeydown::Wrapper::new(control.onkeydown.clone())));
+        tag.add_listener(Rc::new(onfocus::Wrapper::new(control.onfocus.clone())));
+        tag.add_listener(Rc::new(onblur::Wrapper::new(control.onblur.clone())));
+        for (key, value) in control.attrs {
+            tag.add_attribute(key, value.clone());
+        }
+    }
+    node
+}
@@ -0,0 +1,485 @@
+//! A small matcher for the regular expressions of `Rule::Pattern`, compiling patterns to an
+//! automaton which is simulated over all of its states at once, in time linear in the length of
+//! the value.
+//!
+//! This supports the syntax commonly used by the HTML `pattern` attribute: literals, `.`,
+//! character classes such as `[a-z]` & `[^0-9]`, the `\d`, `\w` & `\s` classes & their negations,
+//! character escapes, groups, alternation, the `*`, `+`, `?` & `{n,m}` quantifiers and the `^` &
+//! `$` anchors. Backreferences, lookaround & word boundaries are not supported.
+
+/// Check if the whole of the given value matches the given pattern. Patterns which are not valid
+/// or not supported match any value, as invalid `pattern` attributes are ignored by browsers.
+pub(crate) fn matches(pattern: &str, value: &str) -> bool {
+    let nodes = match Parser::new(pattern).parse() {
+        Some(nodes) => nodes,
+        None => return true,
+    };
+    match compile(&nodes) {
+        Some(program) => run(&program, &value.chars().collect::<Vec<_>>()),
+        None => true,
+    }
+}
+
+/// A part of a parsed pattern.
+#[derive(Debug)]
+enum Node {
+    /// A single character.
+    Char(char),
+    /// Any character other than line terminators, i.e. `.`.
+    Any,
+    /// A character class.
+    Class { items: Vec<ClassItem>, negated: bool },
+    /// The start of the input, i.e. `^`.
+    Start,
+    /// The end of the input, i.e. `$`.
+    End,
+    /// Alternative sequences, e.g. a group.
+    Alternation(Vec<Vec<Node>>),
+    /// A quantified node, with the maximum number of repetitions if any.
+    Repeat { node: Box<Node>, min: usize, max: Option<usize> },
+}
+
+/// An item of a character class.
+#[derive(Debug)]
+enum ClassItem {
+    /// An inclusive range of characters, or a single character.
+    Range(char, char),
+    /// A class escape such as `\d`, along with whether it is negated, e.g. `\D`.
+    Escape(Escape, bool),
+}
+
+/// The class escapes.
+#[derive(Clone, Copy, Debug)]
+enum Escape {
+    Digit,
+    Word,
+    Space,
+}
+
+impl Escape {
+    fn matches(self, c: char) -> bool {
+        match self {
+            Escape::Digit => c.is_ascii_digit(),
+            Escape::Word => c.is_ascii_alphanumeric() || c == '_',
+            Escape::Space => c.is_whitespace() || c == '\u{feff}',
+        }
+    }
+}
+
+impl ClassItem {
+    fn matches(&self, c: char) -> bool {
+        match *self {
+            ClassItem::Range(start, end) => start <= c && c <= end,
+            ClassItem::Escape(escape, negated) => escape.matches(c) != negated,
+        }
+    }
+}
+
+/// A recursive descent parser of patterns, returning `None` for invalid or unsupported syntax.
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn new(pattern: &str) -> Self {
+        Self { chars: pattern.chars().collect(), pos: 0 }
+    }
+
+    fn parse(mut self) -> Option<Vec<Node>> {
+        let node = self.alternation()?;
+        (self.pos == self.chars.len()).then_some(vec![node])
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let eaten = self.peek() == Some(c);
+        if eaten {
+            self.pos += 1;
+        }
+        eaten
+    }
+
+    fn alternation(&mut self) -> Option<Node> {
+        let mut branches = vec![self.sequence()?];
+        while self.eat('|') {
+            branches.push(self.sequence()?);
+        }
+        Some(Node::Alternation(branches))
+    }
+
+    fn sequence(&mut self) -> Option<Vec<Node>> {
+        let mut nodes = Vec::new();
+        while !matches!(self.peek(), None | Some('|') | Some(')')) {
+            let atom = self.atom()?;
+            nodes.push(self.quantified(atom)?);
+        }
+        Some(nodes)
+    }
+
+    fn atom(&mut self) -> Option<Node> {
+        Some(match self.next()? {
+            '.' => Node::Any,
+            '^' => Node::Start,
+            '$' => Node::End,
+            '(' => {
+                if self.eat('?') {
+                    // Only non-capturing & named groups are supported.
+                    if self.eat('<') {
+                        while self.next()? != '>' {}
+                    } else if !self.eat(':') {
+                        return None;
+                    }
+                }
+                let node = self.alternation()?;
+                self.eat(')').then_some(node)?
+            }
+            '[' => self.class()?,
+            '\\' => match self.escape()? {
+                ClassItem::Range(c, _) => Node::Char(c),
+                item => Node::Class { items: vec![item], negated: false },
+            },
+            '*' | '+' | '?' | '{' | '}' | ']' => return None,
+            c => Node::Char(c),
+        })
+    }
+
+    fn quantified(&mut self, node: Node) -> Option<Node> {
+        let (min, max) = if self.eat('*') {
+            (0, None)
+        } else if self.eat('+') {
+            (1, None)
+        } else if self.eat('?') {
+            (0, Some(1))
+        } else if self.eat('{') {
+            let min = self.number()?;
+            let max = if !self.eat(',') {
+                Some(min)
+            } else if self.peek() == Some('}') {
+                None
+            } else {
+                Some(self.number()?)
+            };
+            if !self.eat('}') || max.is_some_and(|max| max < min) {
+                return None;
+            }
+            (min, max)
+        } else {
+            return Some(node);
+        };
+        if matches!(node, Node::Start | Node::End) {
+            return None;
+        }
+        // Lazy quantifiers match the same whole values as greedy ones.
+        self.eat('?');
+        Some(Node::Repeat { node: Box::new(node), min, max })
+    }
+
+    fn number(&mut self) -> Option<usize> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect::<String>().parse().ok()
+    }
+
+    fn class(&mut self) -> Option<Node> {
+        let negated = self.eat('^');
+        let mut items = Vec::new();
+        while !self.eat(']') {
+            let start = self.class_atom()?;
+            let range = self.peek() == Some('-') && !matches!(self.chars.get(self.pos + 1), None | Some(']'));
+            match start {
+                ClassItem::Range(start, _) if range => {
+                    self.pos += 1;
+                    match self.class_atom()? {
+                        ClassItem::Range(end, _) if start <= end => items.push(ClassItem::Range(start, end)),
+                        _ => return None,
+                    }
+                }
+                ClassItem::Escape(..) if range => return None,
+                item => items.push(item),
+            }
+        }
+        Some(Node::Class { items, negated })
+    }
+
+    fn class_atom(&mut self) -> Option<ClassItem> {
+        match self.next()? {
+            '\\' => self.escape(),
+            c => Some(ClassItem::Range(c, c)),
+        }
+    }
+
+    /// Parse the escape following a `\`, as a class item for class escapes & a single character
+    /// range otherwise.
+    fn escape(&mut self) -> Option<ClassItem> {
+        let c = match self.next()? {
+            'd' => return Some(ClassItem::Escape(Escape::Digit, false)),
+            'D' => return Some(ClassItem::Escape(Escape::Digit, true)),
+            'w' => return Some(ClassItem::Escape(Escape::Word, false)),
+            'W' => return Some(ClassItem::Escape(Escape::Word, true)),
+            's' => return Some(ClassItem::Escape(Escape::Space, false)),
+            'S' => return Some(ClassItem::Escape(Escape::Space, true)),
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'f' => '\u{c}',
+            'v' => '\u{b}',
+            '0' if !self.peek().is_some_and(|c| c.is_ascii_digit()) => '\0',
+            'x' => self.hex(2)?,
+            'u' if self.eat('{') => {
+                let start = self.pos;
+                while self.next()? != '}' {}
+                let digits = self.chars[start..self.pos - 1].iter().collect::<String>();
+                char::from_u32(u32::from_str_radix(&digits, 16).ok()?)?
+            }
+            'u' => self.hex(4)?,
+            c if c.is_ascii_punctuation() => c,
+            // Backreferences, word boundaries & other escapes are not supported.
+            _ => return None,
+        };
+        Some(ClassItem::Range(c, c))
+    }
+
+    fn hex(&mut self, len: usize) -> Option<char> {
+        let digits = self.chars.get(self.pos..self.pos + len)?.iter().collect::<String>();
+        self.pos += len;
+        char::from_u32(u32::from_str_radix(&digits, 16).ok()?)
+    }
+}
+
+/// The maximum number of instructions of a compiled pattern, beyond which the pattern is treated
+/// as unsupported, as counted quantifiers are expanded.
+const MAX_PROGRAM: usize = 100_000;
+
+/// An instruction of a pattern compiled to a nondeterministic finite automaton.
+#[derive(Debug)]
+enum Inst<'a> {
+    /// Consume a character matching the given node, i.e. a character, `.` or a class.
+    Consume(&'a Node),
+    /// Continue only at the start of the input.
+    Start,
+    /// Continue only at the end of the input.
+    End,
+    /// Continue at both of the given instructions.
+    Split(usize, usize),
+    /// Continue at the given instruction.
+    Jump(usize),
+    /// The whole pattern matched.
+    Match,
+}
+
+/// Compile the given nodes to the instructions of an automaton, or `None` if the program would
+/// exceed `MAX_PROGRAM`.
+fn compile(nodes: &[Node]) -> Option<Vec<Inst<'_>>> {
+    let mut program = Vec::new();
+    compile_sequence(nodes, &mut program)?;
+    program.push(Inst::Match);
+    Some(program)
+}
+
+fn compile_sequence<'a>(nodes: &'a [Node], program: &mut Vec<Inst<'a>>) -> Option<()> {
+    nodes.iter().try_for_each(|node| compile_node(node, program))
+}
+
+fn compile_node<'a>(node: &'a Node, program: &mut Vec<Inst<'a>>) -> Option<()> {
+    if program.len() > MAX_PROGRAM {
+        return None;
+    }
+    match node {
+        Node::Start => program.push(Inst::Start),
+        Node::End => program.push(Inst::End),
+        Node::Alternation(branches) => {
+            let mut jumps = Vec::new();
+            for (index, branch) in branches.iter().enumerate() {
+                if index + 1 == branches.len() {
+                    compile_sequence(branch, program)?;
+                    break;
+                }
+                let split = program.len();
+                program.push(Inst::Split(split + 1, 0));
+                compile_sequence(branch, program)?;
+                jumps.push(program.len());
+                program.push(Inst::Jump(0));
+                program[split] = Inst::Split(split + 1, program.len());
+            }
+            let end = program.len();
+            for jump in jumps {
+                program[jump] = Inst::Jump(end);
+            }
+        }
+        Node::Repeat { node, min, max } => {
+            for _ in 0..*min {
+                compile_node(node, program)?;
+            }
+            match max {
+                None => {
+                    let split = program.len();
+                    program.push(Inst::Split(split + 1, 0));
+                    compile_node(node, program)?;
+                    program.push(Inst::Jump(split));
+                    program[split] = Inst::Split(split + 1, program.len());
+                }
+                Some(max) => {
+                    let mut splits = Vec::new();
+                    for _ in *min..*max {
+                        splits.push(program.len());
+                        program.push(Inst::Split(program.len() + 1, 0));
+                        compile_node(node, program)?;
+                    }
+                    let end = program.len();
+                    for split in splits {
+                        program[split] = Inst::Split(split + 1, end);
+                    }
+                }
+            }
+        }
+        node => program.push(Inst::Consume(node)),
+    }
+    Some(())
+}
+
+/// Simulate the given program over the given input, following all of its states at once, so that
+/// matching takes time linear in the length of the input without recursion or backtracking.
+fn run(program: &[Inst<'_>], input: &[char]) -> bool {
+    // The position for which each instruction was last added to a list of states, plus one.
+    let mut seen = vec![0; program.len()];
+    let mut states = Vec::new();
+    let mut next = Vec::new();
+    add_state(program, input, &mut seen, &mut states, 0, 0);
+    for pos in 0..=input.len() {
+        if states.is_empty() {
+            return false;
+        }
+        for &pc in &states {
+            match program[pc] {
+                Inst::Match if pos == input.len() => return true,
+                Inst::Consume(node) if input.get(pos).is_some_and(|&c| char_matches(node, c)) => {
+                    add_state(program, input, &mut seen, &mut next, pc + 1, pos + 1);
+                }
+                _ => (),
+            }
+        }
+        std::mem::swap(&mut states, &mut next);
+        next.clear();
+    }
+    false
+}
+
+/// Add the given instruction to the given list of states at the given position, following the
+/// instructions which do not consume a character via an explicit stack.
+fn add_state(program: &[Inst<'_>], input: &[char], seen: &mut [usize], states: &mut Vec<usize>, pc: usize, pos: usize) {
+    let mut stack = vec![pc];
+    while let Some(pc) = stack.pop() {
+        if seen[pc] == pos + 1 {
+            continue;
+        }
+        seen[pc] = pos + 1;
+        match program[pc] {
+            Inst::Jump(target) => stack.push(target),
+            Inst::Split(first, second) => {
+                stack.push(second);
+                stack.push(first);
+            }
+            Inst::Start if pos == 0 => stack.push(pc + 1),
+            Inst::End if pos == input.len() => stack.push(pc + 1),
+            Inst::Start | Inst::End => (),
+            Inst::Consume(_) | Inst::Match => states.push(pc),
+        }
+    }
+}
+
+fn char_matches(node: &Node, c: char) -> bool {
+    match node {
+        Node::Char(expected) => c == *expected,
+        Node::Any => !matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}'),
+        Node::Class { items, negated } => items.iter().any(|item| item.matches(c)) != *negated,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matches;
+
+    #[test]
+    fn whole_values_match() {
+        assert!(matches("abc", "abc"));
+        assert!(!matches("abc", "abcd"));
+        assert!(!matches("abc", "xabc"));
+        assert!(matches("", ""));
+        assert!(matches("a|bc", "bc"));
+        assert!(!matches("a|bc", "ab"));
+        assert!(matches("^a.c$", "a-c"));
+        assert!(!matches(".", "\n"));
+    }
+
+    #[test]
+    fn classes() {
+        assert!(matches("[a-z]+", "hello"));
+        assert!(!matches("[a-z]+", "Hello"));
+        assert!(matches("[^0-9]*", "abc"));
+        assert!(!matches("[^0-9]*", "a1"));
+        assert!(matches("[-.\\w]+", "a-b.c_d"));
+        assert!(matches("\\d{3}-\\d{4}", "555-1234"));
+        assert!(!matches("\\d{3}-\\d{4}", "555-123"));
+        assert!(matches("\\S+\\s\\S+", "Ada Lovelace"));
+        assert!(matches("[é-ü]", "ö"));
+    }
+
+    #[test]
+    fn quantifiers() {
+        assert!(matches("a*ab", "aaab"));
+        assert!(matches("(ab)+", "ababab"));
+        assert!(!matches("(ab)+", "aba"));
+        assert!(matches("(?:a|ab)c", "abc"));
+        assert!(matches("x{2,3}", "xxx"));
+        assert!(!matches("x{2,3}", "xxxx"));
+        assert!(matches("x{2,}", "xxxxx"));
+        assert!(matches("colou?r", "color"));
+        assert!(matches("(a*)*b", "aab"));
+        assert!(matches("(a|aa)*c", "aaac"));
+        assert!(matches(".*?z", "xyz"));
+    }
+
+    #[test]
+    fn escapes() {
+        assert!(matches("\\$\\d+\\.\\d{2}", "$10.99"));
+        assert!(matches("\\u00e9\\x41\\u{1F600}", "éA😀"));
+        assert!(matches("a\\tb", "a\tb"));
+    }
+
+    #[test]
+    fn invalid_patterns_match_anything() {
+        for pattern in ["(a", "a)", "[a", "*a", "a{2,1}", "[z-a]", "\\1", "(?=a)", "a\\b"] {
+            assert!(matches(pattern, "anything"), "{}", pattern);
+        }
+    }
+
+    #[test]
+    fn long_values() {
+        let long = "a".repeat(200_000);
+        assert!(matches("[a-z]*", &long));
+        assert!(matches(".+a", &long));
+        assert!(!matches("[a-z]*b", &long));
+    }
+
+    #[test]
+    fn nested_quantifiers() {
+        let long = "a".repeat(100_000);
+        assert!(!matches("(a*)*b", &long));
+        assert!(!matches("(a|aa)+b", &long));
+        assert!(matches("((a+)+)?", &long));
+        assert!(!matches("(a{2,5}){1000}", &long));
+    }
+}
@@ -2,28 +2,42 @@ use yew::events::InputData;
 use yew::prelude::*;
 use yewtil::NeqAssign;
 
-#[derive(Clone, Debug, Properties, PartialEq)]
-pub struct RadioProps {
-    /// The `name` attribute for this form element.
-    ///
-    /// All members of the same radio group must have the same value for their `name` attribute.
-    pub name: String,
-    /// The `value` attribute for this form element.
-    ///
-    /// This is different from other form elements, as this value does not change. It represents
-    /// the value to be used for the radio group overall when this element is selected.
-    pub value: String,
-    /// The value of the currently selected radio of this radio group.
-    pub checked_value: Option<String>,
-    /// The callback to be used for propagating changes to the selected radio of the radio group.
-    pub update: Callback<String>,
-    #[prop_or_default]
-    pub children: Children,
-    #[prop_or_default]
-    pub classes: Option<Classes>,
-    /// Disable this component.
-    #[prop_or_default]
-    pub disabled: bool,
+use super::validation::{RuleValue, Rules};
+use super::with_control_attrs;
+
+control_props! {
+    #[derive(Clone, Debug, Properties, PartialEq)]
+    pub struct RadioProps {
+        /// The `name` attribute for this form element.
+        ///
+        /// All members of the same radio group must have the same value for their `name` attribute.
+        pub name: String,
+        /// The `value` attribute for this form element.
+        ///
+        /// This is different from other form elements, as this value does not change. It represents
+        /// the value to be used for the radio group overall when this element is selected.
+        pub value: String,
+        /// The value of the currently selected radio of this radio group.
+        pub checked_value: Option<String>,
+        /// The callback to be used for propagating changes to the selected radio of the radio
+        /// group.
+        pub update: Callback<String>,
+        #[prop_or_default]
+        pub children: Children,
+        #[prop_or_default]
+        pub classes: Option<Classes>,
+        /// Disable this component.
+        #[prop_or_default]
+        pub disabled: bool,
+        /// Validation rules for the value of this component, adding the `is-success` or `is-danger`
+        /// class once the value has been changed.
+        #[prop_or_default]
+        pub rules: Option<Rules>,
+        /// The callback to be used for propagating the validation result of each new value, given
+        /// the `rules` of this component.
+        #[prop_or_else(Callback::noop)]
+        pub onvalidate: Callback<Result<(), String>>,
+    }
 }
 
 /// The mutually exclusive radio buttons in their native format.
@@ -36,6 +50,8 @@ pub struct RadioProps {
 pub struct Radio {
     props: RadioProps,
     link: ComponentLink<Self>,
+    /// Whether the value has been changed by the user, after which its validity is displayed.
+    touched: bool,
 }
 
 impl Component for Radio {
@@ -43,10 +59,14 @@ impl Component for Radio {
     type Properties = RadioProps;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Self { props, link }
+        Self { props, link, touched: false }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        if let Some(rules) = &self.props.rules {
+            self.props.onvalidate.emit(rules.check(&msg));
+        }
+        self.touched = true;
         self.props.update.emit(msg);
         false
     }
@@ -58,16 +78,22 @@ impl Component for Radio {
     fn view(&self) -> Html {
         let mut classes = Classes::from("radio");
         classes.push(&self.props.classes);
+        if let (true, Some(rules)) = (self.touched, &self.props.rules) {
+            classes.push(rules.class(&self.props.checked_value.rule_value()));
+        }
+        let input = html! {
+            <input
+                type="radio"
+                name=self.props.name.clone()
+                value=self.props.value.clone()
+                checked=self.props.checked_value.as_ref().map(|val| val == &self.props.value).unwrap_or(false)
+                oninput=self.link.callback(|data: InputData| data.value)
+                disabled=self.props.disabled
+                />
+        };
         html! {
             <label class=classes disabled=self.props.disabled>
-                <input
-                    type="radio"
-                    name=self.props.name.clone()
-                    value=self.props.value.clone()
-                    checked=self.props.checked_value.as_ref().map(|val| val == &self.props.value).unwrap_or(false)
-                    oninput=self.link.callback(|data: InputData| data.value)
-                    disabled=self.props.disabled
-                    />
+                {with_control_attrs(input, control_attrs!(self.props))}
                 {self.props.children.clone()}
             </label>
         }
@@ -4,32 +4,47 @@ use yew::events::ChangeData;
 use yew::prelude::*;
 use yewtil::NeqAssign;
 
+use super::validation::Rules;
+use super::with_control_attrs;
 use crate::Size;
 
-#[derive(Clone, Debug, Properties, PartialEq)]
-pub struct SelectProps {
-    /// The `name` attribute for this form element.
-    pub name: String,
-    /// The controlled value of this form element.
-    pub value: String,
-    /// The callback to be used for propagating changes to this element's value.
-    pub update: Callback<String>,
-
-    /// The `option` & `optgroup` tags of this select component.
-    #[prop_or_default]
-    pub children: Children,
-    #[prop_or_default]
-    pub classes: Option<Classes>,
-
-    /// The size of this component.
-    #[prop_or_default]
-    pub size: Option<Size>,
-    /// Display a loading spinner within this component.
-    #[prop_or_default]
-    pub loading: bool,
-    /// Disable this component.
-    #[prop_or_default]
-    pub disabled: bool,
+control_props! {
+    #[derive(Clone, Debug, Properties, PartialEq)]
+    pub struct SelectProps {
+        /// The `name` attribute for this form element.
+        pub name: String,
+        /// The controlled value of this form element.
+        pub value: String,
+        /// The callback to be used for propagating changes to this element's value.
+        pub update: Callback<String>,
+
+        /// The `option` & `optgroup` tags of this select component.
+        #[prop_or_default]
+        pub children: Children,
+        #[prop_or_default]
+        pub classes: Option<Classes>,
+
+        /// The size of this component.
+        #[prop_or_default]
+        pub size: Option<Size>,
+        /// Display a loading spinner within this component.
+        #[prop_or_default]
+        pub loading: bool,
+        /// Disable this component.
+        #[prop_or_default]
+        pub disabled: bool,
+        /// Validation rules for the value of this component, adding the `is-success` or `is-danger`
+        /// class once the value has been changed.
+        #[prop_or_default]
+        pub rules: Option<Rules>,
+        /// The callback to be used for propagating the validation result of each new value, given
+        /// the `rules` of this component.
+        #[prop_or_else(Callback::noop)]
+        pub onvalidate: Callback<Result<(), String>>,
+        /// The `autocomplete` attribute for this form element, e.g. `email` or `off`.
+        #[prop_or_default]
+        pub autocomplete: Option<String>,
+    }
 }
 
 /// A wrapper around an HTML `select` tag.
@@ -42,10 +57,12 @@ pub struct SelectProps {
 ///
 /// **NOTE WELL:** not all browsers will honor the value of the select element's value on initial
 /// load. So if you have an initial `value` set for this component, ensure that the corresponding
-/// option element also has the `selected=true` attribute.
+/// option element also has the `selected=true` attribute, or use a `TypedSelect`.
 pub struct Select {
     props: SelectProps,
     link: ComponentLink<Self>,
+    /// Whether the value has been changed by the user, after which its validity is displayed.
+    touched: bool,
 }
 
 impl Component for Select {
@@ -53,10 +70,14 @@ impl Component for Select {
     type Properties = SelectProps;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Self { props, link }
+        Self { props, link, touched: false }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        if let Some(rules) = &self.props.rules {
+            self.props.onvalidate.emit(rules.check(&msg));
+        }
+        self.touched = true;
         self.props.update.emit(msg);
         false
     }
@@ -68,25 +89,32 @@ impl Component for Select {
     fn view(&self) -> Html {
         let mut classes = Classes::from("select");
         classes.push(&self.props.classes);
+        if let (true, Some(rules)) = (self.touched, &self.props.rules) {
+            classes.push(rules.class(&self.props.value));
+        }
         if let Some(size) = &self.props.size {
             classes.push(&size.to_string());
         }
         if self.props.loading {
             classes.push("is-loading");
         }
+        let select = html! {
+            <select
+                name=self.props.name.clone()
+                value=self.props.value.clone()
+                disabled=self.props.disabled
+                autocomplete=self.props.autocomplete.clone()
+                onchange=self.link.callback(|change: ChangeData| match change {
+                    ChangeData::Select(data) => data.value(),
+                    _ => unreachable!("invariant violation: received non-select change event from a select element"),
+                })
+            >
+                {self.props.children.clone()}
+            </select>
+        };
         html! {
             <div class=classes>
-                <select
-                    name=self.props.name.clone()
-                    value=self.props.value.clone()
-                    disabled=self.props.disabled
-                    onchange=self.link.callback(|change: ChangeData| match change {
-                        ChangeData::Select(data) => data.value(),
-                        _ => unreachable!("invariant violation: received non-select change event from a select element"),
-                    })
-                >
-                    {self.props.children.clone()}
-                </select>
+                {with_control_attrs(select, control_attrs!(self.props))}
             </div>
         }
     }
@@ -95,33 +123,38 @@ impl Component for Select {
 //////////////////////////////////////////////////////////////////////////////
 //////////////////////////////////////////////////////////////////////////////
 
-#[derive(Properties, Clone, PartialEq)]
-pub struct MultiSelectProps {
-    /// The `name` attribute for this form element.
-    pub name: String,
-    /// The controlled value of this form element.
-    pub value: Vec<String>,
-    /// The callback to be used for propagating changes to this element's value.
-    pub update: Callback<Vec<String>>,
-
-    /// The `option` & `optgroup` tags of this select component.
-    #[prop_or_default]
-    pub children: Children,
-    #[prop_or_default]
-    pub classes: Option<Classes>,
-
-    /// The size of this component.
-    #[prop_or_default]
-    pub size: Option<Size>,
-    /// Size of the list to display.
-    #[prop_or_else(|| 4)]
-    pub list_size: u32,
-    /// Display a loading spinner within this component.
-    #[prop_or_default]
-    pub loading: bool,
-    /// Disable this component.
-    #[prop_or_default]
-    pub disabled: bool,
+control_props! {
+    #[derive(Properties, Clone, PartialEq)]
+    pub struct MultiSelectProps {
+        /// The `name` attribute for this form element.
+        pub name: String,
+        /// The controlled value of this form element.
+        pub value: Vec<String>,
+        /// The callback to be used for propagating changes to this element's value.
+        pub update: Callback<Vec<String>>,
+
+        /// The `option` & `optgroup` tags of this select component.
+        #[prop_or_default]
+        pub children: Children,
+        #[prop_or_default]
+        pub classes: Option<Classes>,
+
+        /// The size of this component.
+        #[prop_or_default]
+        pub size: Option<Size>,
+        /// Size of the list to display.
+        #[prop_or_else(|| 4)]
+        pub list_size: u32,
+        /// Display a loading spinner within this component.
+        #[prop_or_default]
+        pub loading: bool,
+        /// Disable this component.
+        #[prop_or_default]
+        pub disabled: bool,
+        /// The `autocomplete` attribute for this form element, e.g. `email` or `off`.
+        #[prop_or_default]
+        pub autocomplete: Option<String>,
+    }
 }
 
 /// A wrapper around an HTML `select` tag with the `multiple=true` attribute.
@@ -134,7 +167,7 @@ pub struct MultiSelectProps {
 ///
 /// **NOTE WELL:** not all browsers will honor the value of the select element's value on initial
 /// load. So if you have an initial `value` set for this component, ensure that the corresponding
-/// option element also has the `selected=true` attribute.
+/// option element also has the `selected=true` attribute, or use a `TypedMultiSelect`.
 pub struct MultiSelect {
     props: MultiSelectProps,
     link: ComponentLink<Self>,
@@ -168,28 +201,308 @@ impl Component for MultiSelect {
         }
 
         let size: String = self.props.list_size.to_string();
+        let select = html! {
+            <select
+                multiple=true
+                size=size
+                name=self.props.name.clone()
+                value=self.props.value.join(",")
+                disabled=self.props.disabled
+                autocomplete=self.props.autocomplete.clone()
+                onchange=self.link.callback(|change: ChangeData| match change {
+                    ChangeData::Select(data) => {
+                        let opts = data.selected_options();
+                        (0..opts.length()).into_iter()
+                            .filter_map(|idx| opts.item(idx))
+                            .filter_map(|elem| elem.get_attribute("value").or_else(|| elem.text_content()))
+                            .collect::<Vec<_>>()
+                    }
+                    _ => unreachable!("invariant violation: received non-select change event from a select element"),
+                })
+            >
+                {self.props.children.clone()}
+            </select>
+        };
         html! {
             <div class=classes>
-                <select
-                    multiple=true
-                    size=size
-                    name=self.props.name.clone()
-                    value=self.props.value.join(",")
-                    disabled=self.props.disabled
-                    onchange=self.link.callback(|change: ChangeData| match change {
-                        ChangeData::Select(data) => {
-                            let opts = data.selected_options();
-                            (0..opts.length()).into_iter()
-                                .filter_map(|idx| opts.item(idx))
-                                .filter_map(|elem| elem.get_attribute("value").or_else(|| elem.text_content()))
-                                .collect::<Vec<_>>()
-                        }
-                        _ => unreachable!("invariant violation: received non-select change event from a select element"),
-                    })
-                >
-                    {self.props.children.clone()}
-                </select>
+                {with_control_attrs(select, control_attrs!(self.props))}
             </div>
         }
     }
 }
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+control_props! {
+    #[derive(Clone, Debug, Properties, PartialEq)]
+    pub struct TypedSelectProps<T: Clone + PartialEq + 'static> {
+        /// The `name` attribute for this form element.
+        pub name: String,
+        /// The controlled value of this form element.
+        pub value: T,
+        /// The callback to be used for propagating changes to this element's value.
+        pub update: Callback<T>,
+        /// The options of this component, as pairs of the option's value & label.
+        #[prop_or_default]
+        pub options: Vec<(T, String)>,
+        /// Groups of options, as pairs of the group's label & its options, rendered as `optgroup`
+        /// tags after the ungrouped `options`.
+        #[prop_or_default]
+        pub groups: Vec<(String, Vec<(T, String)>)>,
+        /// The options which can not be selected.
+        #[prop_or_default]
+        pub disabled_options: Vec<T>,
+
+        #[prop_or_default]
+        pub classes: Option<Classes>,
+        /// The size of this component.
+        #[prop_or_default]
+        pub size: Option<Size>,
+        /// Display a loading spinner within this component.
+        #[prop_or_default]
+        pub loading: bool,
+        /// Disable this component.
+        #[prop_or_default]
+        pub disabled: bool,
+        /// The `autocomplete` attribute for this form element, e.g. `email` or `off`.
+        #[prop_or_default]
+        pub autocomplete: Option<String>,
+    }
+}
+
+/// A wrapper around an HTML `select` tag, for values of type `T`.
+///
+/// [https://bulma.io/documentation/form/select/](https://bulma.io/documentation/form/select/)
+///
+/// The options are given as data rather than as `option` tags, and the option equal to the
+/// controlled `value` is marked as selected, so the initial value is always honored.
+pub struct TypedSelect<T: Clone + PartialEq + 'static> {
+    props: TypedSelectProps<T>,
+    link: ComponentLink<Self>,
+}
+
+impl<T: Clone + PartialEq + 'static> Component for TypedSelect<T> {
+    type Message = String;
+    type Properties = TypedSelectProps<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { props, link }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        if let Some(value) = find_typed_option(&self.props.options, &self.props.groups, &msg) {
+            self.props.update.emit(value);
+        }
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = Classes::from("select");
+        classes.push(&self.props.classes);
+        if let Some(size) = &self.props.size {
+            classes.push(size.to_string());
+        }
+        if self.props.loading {
+            classes.push("is-loading");
+        }
+        let value = typed_options(&self.props.options, &self.props.groups)
+            .position(|(option, _)| option == &self.props.value)
+            .map(|index| index.to_string())
+            .unwrap_or_default();
+        let options = view_typed_options(&self.props.options, &self.props.groups, &self.props.disabled_options, |option| {
+            option == &self.props.value
+        });
+        let select = html! {
+            <select
+                name=self.props.name.clone()
+                value=value
+                disabled=self.props.disabled
+                autocomplete=self.props.autocomplete.clone()
+                onchange=self.link.callback(|change: ChangeData| match change {
+                    ChangeData::Select(data) => data.value(),
+                    _ => unreachable!("invariant violation: received non-select change event from a select element"),
+                })
+            >
+                {options}
+            </select>
+        };
+        html! {
+            <div class=classes>
+                {with_control_attrs(select, control_attrs!(self.props))}
+            </div>
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+control_props! {
+    #[derive(Clone, Debug, Properties, PartialEq)]
+    pub struct TypedMultiSelectProps<T: Clone + PartialEq + 'static> {
+        /// The `name` attribute for this form element.
+        pub name: String,
+        /// The controlled value of this form element.
+        pub value: Vec<T>,
+        /// The callback to be used for propagating changes to this element's value.
+        pub update: Callback<Vec<T>>,
+        /// The options of this component, as pairs of the option's value & label.
+        #[prop_or_default]
+        pub options: Vec<(T, String)>,
+        /// Groups of options, as pairs of the group's label & its options, rendered as `optgroup`
+        /// tags after the ungrouped `options`.
+        #[prop_or_default]
+        pub groups: Vec<(String, Vec<(T, String)>)>,
+        /// The options which can not be selected.
+        #[prop_or_default]
+        pub disabled_options: Vec<T>,
+
+        #[prop_or_default]
+        pub classes: Option<Classes>,
+        /// The size of this component.
+        #[prop_or_default]
+        pub size: Option<Size>,
+        /// Size of the list to display.
+        #[prop_or_else(|| 4)]
+        pub list_size: u32,
+        /// Display a loading spinner within this component.
+        #[prop_or_default]
+        pub loading: bool,
+        /// Disable this component.
+        #[prop_or_default]
+        pub disabled: bool,
+        /// The `autocomplete` attribute for this form element, e.g. `email` or `off`.
+        #[prop_or_default]
+        pub autocomplete: Option<String>,
+    }
+}
+
+/// A wrapper around an HTML `select` tag with the `multiple=true` attribute, for lists of values
+/// of type `T`.
+///
+/// [https://bulma.io/documentation/form/select/](https://bulma.io/documentation/form/select/)
+///
+/// The options are given as data rather than as `option` tags, and the options contained in the
+/// controlled `value` are marked as selected, so the initial value is always honored. The selected
+/// values are propagated in the order of the options.
+pub struct TypedMultiSelect<T: Clone + PartialEq + 'static> {
+    props: TypedMultiSelectProps<T>,
+    link: ComponentLink<Self>,
+}
+
+impl<T: Clone + PartialEq + 'static> Component for TypedMultiSelect<T> {
+    type Message = Vec<String>;
+    type Properties = TypedMultiSelectProps<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { props, link }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let values = msg
+            .iter()
+            .filter_map(|index| find_typed_option(&self.props.options, &self.props.groups, index))
+            .collect();
+        self.props.update.emit(values);
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = Classes::from("select is-multiple");
+        classes.push(&self.props.classes);
+        if let Some(size) = &self.props.size {
+            classes.push(size.to_string());
+        }
+        if self.props.loading {
+            classes.push("is-loading");
+        }
+        let options = view_typed_options(&self.props.options, &self.props.groups, &self.props.disabled_options, |option| {
+            self.props.value.contains(option)
+        });
+        let select = html! {
+            <select
+                multiple=true
+                size=self.props.list_size.to_string()
+                name=self.props.name.clone()
+                disabled=self.props.disabled
+                autocomplete=self.props.autocomplete.clone()
+                onchange=self.link.callback(|change: ChangeData| match change {
+                    ChangeData::Select(data) => {
+                        let opts = data.selected_options();
+                        (0..opts.length())
+                            .filter_map(|idx| opts.item(idx))
+                            .filter_map(|elem| elem.get_attribute("value"))
+                            .collect::<Vec<_>>()
+                    }
+                    _ => unreachable!("invariant violation: received non-select change event from a select element"),
+                })
+            >
+                {options}
+            </select>
+        };
+        html! {
+            <div class=classes>
+                {with_control_attrs(select, control_attrs!(self.props))}
+            </div>
+        }
+    }
+}
+
+/// All options of a typed select, with the ungrouped options first. The `value` attribute of each
+/// option element is its index in this order.
+fn typed_options<'a, T>(options: &'a [(T, String)], groups: &'a [(String, Vec<(T, String)>)]) -> impl Iterator<Item = &'a (T, String)> {
+    options.iter().chain(groups.iter().flat_map(|(_, group)| group))
+}
+
+/// Find the option of a typed select with the given `value` attribute.
+fn find_typed_option<T: Clone>(options: &[(T, String)], groups: &[(String, Vec<(T, String)>)], value: &str) -> Option<T> {
+    let index = value.parse::<usize>().ok()?;
+    typed_options(options, groups).nth(index).map(|(option, _)| option.clone())
+}
+
+/// Render the `option` & `optgroup` tags of a typed select.
+fn view_typed_options<T: PartialEq>(
+    options: &[(T, String)], groups: &[(String, Vec<(T, String)>)], disabled: &[T], selected: impl Fn(&T) -> bool,
+) -> Html {
+    let view_options = |options: &[(T, String)], offset: usize| {
+        options
+            .iter()
+            .enumerate()
+            .map(|(index, (option, label))| {
+                html! {
+                    <option
+                        value=(offset + index).to_string()
+                        selected=selected(option)
+                        disabled=disabled.contains(option)
+                    >
+                        {label}
+                    </option>
+                }
+            })
+            .collect::<Html>()
+    };
+    let mut offset = options.len();
+    let groups = groups
+        .iter()
+        .map(|(label, group)| {
+            let group_html = html! {<optgroup label=label.clone()>{view_options(group, offset)}</optgroup>};
+            offset += group.len();
+            group_html
+        })
+        .collect::<Html>();
+    html! {
+        <>
+            {view_options(options, 0)}
+            {groups}
+        </>
+    }
+}
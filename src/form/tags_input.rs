@@ -0,0 +1,352 @@
+#![allow(clippy::unnecessary_operation)]
+
+use derive_more::Display;
+use wasm_bindgen::JsCast;
+use web_sys::{ClipboardEvent, HtmlInputElement};
+use yew::prelude::*;
+use yewtil::NeqAssign;
+
+use super::validation::Rules;
+use super::with_control_attrs;
+use crate::{Size, Tag, Tags};
+
+control_props! {
+    #[derive(Clone, Debug, Properties, PartialEq)]
+    pub struct TagsInputProps {
+        /// The `name` attribute for this form element.
+        pub name: String,
+        /// The controlled value of this form element.
+        pub value: Vec<String>,
+        /// The callback to be used for propagating changes to this element's value.
+        pub update: Callback<Vec<String>>,
+        /// The callback to be used for propagating why an entered value was not added.
+        #[prop_or_else(Callback::noop)]
+        pub onerror: Callback<TagError>,
+
+        #[prop_or_default]
+        pub classes: Option<Classes>,
+        /// Extra classes for each of the tags, e.g. `is-info`.
+        #[prop_or_default]
+        pub tag_classes: Option<Classes>,
+        /// The placeholder value for this component.
+        #[prop_or_default]
+        pub placeholder: String,
+        /// The size of this component.
+        #[prop_or_default]
+        pub size: Option<Size>,
+        /// Disable this component.
+        #[prop_or_default]
+        pub disabled: bool,
+        /// Validation rules for each entered value; values which are not valid are not added.
+        #[prop_or_default]
+        pub rules: Option<Rules>,
+        /// Allow the same value to be added more than once.
+        #[prop_or_default]
+        pub allow_duplicates: bool,
+        /// The maximum number of values.
+        #[prop_or_default]
+        pub max_tags: Option<usize>,
+        /// Values suggested as the user types, via a `datalist`. Values already added are not
+        /// suggested.
+        #[prop_or_default]
+        pub suggestions: Vec<String>,
+    }
+}
+
+/// The reason an entered value was not added to a `TagsInput`.
+#[derive(Clone, Debug, Display, PartialEq)]
+pub enum TagError {
+    /// The value did not satisfy the rules of the component, for the given reason.
+    #[display(fmt = "{}", error)]
+    Invalid { value: String, error: String },
+    /// The value had already been added.
+    #[display(fmt = "\"{}\" has already been added", _0)]
+    Duplicate(String),
+    /// The maximum number of values had already been added.
+    #[display(fmt = "At most {} values can be added", max)]
+    TooMany { value: String, max: usize },
+}
+
+impl std::error::Error for TagError {}
+
+/// The message type used by the `TagsInput` component.
+pub enum TagsInputMsg {
+    /// The text of the input changed.
+    Input(String),
+    /// Text containing commas or newlines was pasted into the input, resulting in the given text.
+    Paste(String),
+    /// `Enter` was pressed within the input.
+    Enter,
+    /// `Backspace` was pressed within the empty input.
+    Backspace,
+    /// The value at the given index was deleted.
+    Remove(usize),
+}
+
+/// An input for lists of text values, displayed as `Tag`s with delete buttons.
+///
+/// [https://bulma.io/documentation/elements/tag/](https://bulma.io/documentation/elements/tag/)
+///
+/// Values are added when `Enter` or a comma is pressed, and pasted text containing commas or
+/// newlines is split into several values. `Backspace` within the empty input deletes the last
+/// value.
+///
+/// Entered values are trimmed, and values which do not satisfy the `rules`, duplicate values &
+/// values beyond `max_tags` are not added; their text remains at the start of the input, where it
+/// is not checked again until edited, & the reason is propagated via `onerror`. The common form
+/// control props, such as `id` & `onblur`, are those of the text input.
+pub struct TagsInput {
+    props: TagsInputProps,
+    link: ComponentLink<Self>,
+    /// The current text of the input.
+    text: String,
+    /// The rejected values at the start of the text, each followed by a comma.
+    held: String,
+}
+
+impl Component for TagsInput {
+    type Message = TagsInputMsg;
+    type Properties = TagsInputProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            props,
+            link,
+            text: String::new(),
+            held: String::new(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            TagsInputMsg::Input(text) => {
+                let held = self.take_held(&text);
+                let typed = &text[held.len()..];
+                if let Some((values, rest)) = typed.rsplit_once(',') {
+                    // The text after the last comma is still being typed.
+                    let rejected = self.add(values.split(','));
+                    self.hold(held, rejected, rest.trim_start());
+                } else {
+                    self.held = held;
+                    self.text = text;
+                }
+            }
+            TagsInputMsg::Paste(text) => {
+                let held = self.take_held(&text);
+                let rejected = self.add(text[held.len()..].split([',', '\n']));
+                self.hold(held, rejected, "");
+            }
+            TagsInputMsg::Enter => {
+                let held = std::mem::take(&mut self.held);
+                let text = std::mem::take(&mut self.text);
+                let rejected = self.add(std::iter::once(&text[held.len()..]));
+                self.hold(held, rejected, "");
+            }
+            TagsInputMsg::Backspace => {
+                let mut value = self.props.value.clone();
+                if value.pop().is_none() {
+                    return false;
+                }
+                self.props.update.emit(value);
+            }
+            TagsInputMsg::Remove(index) => {
+                let mut value = self.props.value.clone();
+                if index >= value.len() {
+                    return false;
+                }
+                value.remove(index);
+                self.props.update.emit(value);
+            }
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = Classes::from("input");
+        classes.push(&self.props.classes);
+        if let Some(size) = &self.props.size {
+            classes.push(size.to_string());
+        }
+
+        let tags = self
+            .props
+            .value
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let delete = (!self.props.disabled).then(|| {
+                    let onclick = self.link.callback(move |_| TagsInputMsg::Remove(index));
+                    let label = format!("Remove {}", value);
+                    html! {<button type="button" class="delete is-small" aria-label=label onclick=onclick></button>}
+                });
+                html! {
+                    <Tag classes=self.props.tag_classes.clone()>
+                        {value}
+                        {delete.unwrap_or_default()}
+                    </Tag>
+                }
+            })
+            .collect::<Html>();
+
+        let list_id = (!self.props.suggestions.is_empty()).then(|| format!("{}-suggestions", self.props.id.as_ref().unwrap_or(&self.props.name)));
+        let datalist = match &list_id {
+            Some(list_id) => {
+                let options = self
+                    .props
+                    .suggestions
+                    .iter()
+                    .filter(|suggestion| !self.props.value.contains(suggestion))
+                    .map(|suggestion| html! {<option value=suggestion.clone()></option>})
+                    .collect::<Html>();
+                html! {<datalist id=list_id.clone()>{options}</datalist>}
+            }
+            None => html! {},
+        };
+
+        let empty = self.text.is_empty();
+        let onkeydown = self
+            .link
+            .batch_callback(move |event: KeyboardEvent| match event.key().as_str() {
+                "Enter" if !empty => {
+                    // Keep the enclosing form from being submitted.
+                    event.prevent_default();
+                    Some(TagsInputMsg::Enter)
+                }
+                "Backspace" if empty => Some(TagsInputMsg::Backspace),
+                _ => None,
+            });
+        // Single-line inputs drop the newlines of pasted text, so text containing separators is
+        // read from the clipboard & split rather than pasted.
+        let onpaste = self.link.batch_callback(|event: Event| {
+            let pasted = event
+                .dyn_ref::<ClipboardEvent>()?
+                .clipboard_data()?
+                .get_data("text")
+                .ok()?;
+            if !pasted.contains([',', '\n']) {
+                return None;
+            }
+            let input = event.target()?.dyn_into::<HtmlInputElement>().ok()?;
+            event.prevent_default();
+            let text = input.value();
+            let start = input
+                .selection_start()
+                .ok()
+                .flatten()
+                .map_or(text.len(), |offset| byte_offset(&text, offset));
+            let end = input
+                .selection_end()
+                .ok()
+                .flatten()
+                .map_or(start, |offset| byte_offset(&text, offset))
+                .max(start);
+            Some(TagsInputMsg::Paste(format!("{}{}{}", &text[..start], pasted, &text[end..])))
+        });
+        let input = html! {
+            <input
+                type="text"
+                name=self.props.name.clone()
+                value=self.text.clone()
+                placeholder=self.props.placeholder.clone()
+                disabled=self.props.disabled
+                list=list_id
+                style="flex:1 1 8em;min-width:0;border:none;outline:none;box-shadow:none;background:transparent;font:inherit;color:inherit;"
+                oninput=self.link.callback(|input: InputData| TagsInputMsg::Input(input.value))
+                onpaste=onpaste
+                onkeydown=onkeydown
+                />
+        };
+        html! {
+            <div class=classes style="height:auto;flex-wrap:wrap;gap:0.25em;">
+                <Tags classes=classes!("mb-0")>{tags}</Tags>
+                {with_control_attrs(input, control_attrs!(self.props))}
+                {datalist}
+            </div>
+        }
+    }
+}
+
+impl TagsInput {
+    /// Add the given entered values, propagating the new value if any were added, & returning the
+    /// text of those which were not.
+    fn add<'a>(&self, entered: impl Iterator<Item = &'a str>) -> String {
+        let mut value = self.props.value.clone();
+        let mut rejected = Vec::new();
+        for entry in entered.map(str::trim).filter(|entry| !entry.is_empty()) {
+            match self.reject(&value, entry) {
+                Some(error) => {
+                    self.props.onerror.emit(error);
+                    rejected.push(entry);
+                }
+                None => value.push(entry.to_string()),
+            }
+        }
+        if value.len() != self.props.value.len() {
+            self.props.update.emit(value);
+        }
+        rejected.join(", ")
+    }
+
+    /// Take the held rejected values, unless their text was edited within the given new text.
+    fn take_held(&mut self, text: &str) -> String {
+        // Rejected values at the start of the text are only checked again once edited.
+        if text.starts_with(self.held.as_str()) {
+            std::mem::take(&mut self.held)
+        } else {
+            String::new()
+        }
+    }
+
+    /// Keep the given rejected values at the start of the text, after those already held, followed
+    /// by the given text still being typed.
+    fn hold(&mut self, mut held: String, rejected: String, rest: &str) {
+        if !rejected.is_empty() {
+            held.push_str(&rejected);
+            held.push_str(", ");
+        }
+        self.text = format!("{}{}", held, rest);
+        self.held = held;
+    }
+
+    /// Check why the given entered value can not be added to the given values, if at all.
+    fn reject(&self, value: &[String], entry: &str) -> Option<TagError> {
+        if let Some(Err(error)) = self.props.rules.as_ref().map(|rules| rules.check(entry)) {
+            return Some(TagError::Invalid { value: entry.to_string(), error });
+        }
+        if !self.props.allow_duplicates && value.iter().any(|existing| existing == entry) {
+            return Some(TagError::Duplicate(entry.to_string()));
+        }
+        let max = self.props.max_tags.filter(|max| value.len() >= *max)?;
+        Some(TagError::TooMany { value: entry.to_string(), max })
+    }
+}
+
+/// The byte offset within the given text of the given offset in UTF-16 code units, as used for the
+/// selection of inputs.
+fn byte_offset(text: &str, utf16_offset: u32) -> usize {
+    let mut units = 0;
+    for (index, c) in text.char_indices() {
+        if units >= utf16_offset as usize {
+            return index;
+        }
+        units += c.len_utf16();
+    }
+    text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::byte_offset;
+
+    #[test]
+    fn byte_offsets_of_utf16_offsets() {
+        assert_eq!(byte_offset("abc", 1), 1);
+        assert_eq!(byte_offset("é😀x", 1), 2);
+        assert_eq!(byte_offset("é😀x", 3), 6);
+        assert_eq!(byte_offset("abc", 10), 3);
+    }
+}
@@ -2,45 +2,70 @@ use yew::events::InputData;
 use yew::prelude::*;
 use yewtil::NeqAssign;
 
+use super::validation::Rules;
+use super::with_control_attrs;
 use crate::Size;
 use std::borrow::Cow;
 
-#[derive(Clone, Debug, Properties, PartialEq)]
-pub struct TextAreaProps {
-    /// The `name` attribute for this form element.
-    pub name: String,
-    /// The controlled value of this form element.
-    pub value: String,
-    /// The callback to be used for propagating changes to this element's value.
-    pub update: Callback<String>,
+control_props! {
+    #[derive(Clone, Debug, Properties, PartialEq)]
+    pub struct TextAreaProps {
+        /// The `name` attribute for this form element.
+        pub name: String,
+        /// The controlled value of this form element.
+        pub value: String,
+        /// The callback to be used for propagating changes to this element's value.
+        pub update: Callback<String>,
 
-    #[prop_or_default]
-    pub classes: Option<Classes>,
-    /// The placeholder value for this component.
-    #[prop_or_default]
-    pub placeholder: String,
-    /// The number of rows to which this component will be locked.
-    #[prop_or_default]
-    pub rows: u32,
+        #[prop_or_default]
+        pub classes: Option<Classes>,
+        /// The placeholder value for this component.
+        #[prop_or_default]
+        pub placeholder: String,
+        /// The number of rows to which this component will be locked.
+        #[prop_or_default]
+        pub rows: u32,
 
-    /// The size of this component.
-    #[prop_or_default]
-    pub size: Option<Size>,
-    /// Fix the size of this component.
-    #[prop_or_default]
-    pub fixed_size: bool,
-    /// Display a loading spinner within this component.
-    #[prop_or_default]
-    pub loading: bool,
-    /// Disable this component.
-    #[prop_or_default]
-    pub disabled: bool,
-    /// Make this component read-only.
-    #[prop_or_default]
-    pub readonly: bool,
-    /// Make this component static.
-    #[prop_or_default]
-    pub r#static: bool,
+        /// The size of this component.
+        #[prop_or_default]
+        pub size: Option<Size>,
+        /// Fix the size of this component.
+        #[prop_or_default]
+        pub fixed_size: bool,
+        /// Display a loading spinner within this component.
+        #[prop_or_default]
+        pub loading: bool,
+        /// Disable this component.
+        #[prop_or_default]
+        pub disabled: bool,
+        /// Make this component read-only.
+        #[prop_or_default]
+        pub readonly: bool,
+        /// Make this component static.
+        #[prop_or_default]
+        pub r#static: bool,
+        /// Validation rules for the value of this component, adding the `is-success` or `is-danger`
+        /// class once the value has been changed.
+        #[prop_or_default]
+        pub rules: Option<Rules>,
+        /// The callback to be used for propagating the validation result of each new value, given
+        /// the `rules` of this component.
+        #[prop_or_else(Callback::noop)]
+        pub onvalidate: Callback<Result<(), String>>,
+        /// The `autocomplete` attribute for this form element, e.g. `email` or `off`.
+        #[prop_or_default]
+        pub autocomplete: Option<String>,
+        /// The maximum number of characters of the value of this component.
+        #[prop_or_default]
+        pub maxlength: Option<u32>,
+        /// The minimum number of characters of the value of this component.
+        #[prop_or_default]
+        pub minlength: Option<u32>,
+        /// The `inputmode` attribute for this form element, hinting at the virtual keyboard to
+        /// display.
+        #[prop_or_default]
+        pub inputmode: Option<String>,
+    }
 }
 
 /// A multiline textarea component.
@@ -52,6 +77,8 @@ pub struct TextAreaProps {
 /// component via callback.
 pub struct TextArea {
     link: ComponentLink<Self>,
+    /// Whether the value has been changed by the user, after which its validity is displayed.
+    touched: bool,
     props: TextAreaProps,
 }
 
@@ -60,10 +87,14 @@ impl Component for TextArea {
     type Properties = TextAreaProps;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Self { link, props }
+        Self { link, props, touched: false }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        if let Some(rules) = &self.props.rules {
+            self.props.onvalidate.emit(rules.check(&msg));
+        }
+        self.touched = true;
         self.props.update.emit(msg);
         false
     }
@@ -75,6 +106,9 @@ impl Component for TextArea {
     fn view(&self) -> Html {
         let mut classes = Classes::from("textarea");
         classes.push(&self.props.classes);
+        if let (true, Some(rules)) = (self.touched, &self.props.rules) {
+            classes.push(rules.class(&self.props.value));
+        }
         if let Some(size) = &self.props.size {
             classes.push(&size.to_string());
         }
@@ -89,7 +123,7 @@ impl Component for TextArea {
         }
 
         let rows = Cow::from(self.props.rows.to_string());
-        html! {
+        let textarea = html! {
             <textarea
                 name=self.props.name.clone()
                 value=self.props.value.clone()
@@ -99,7 +133,12 @@ impl Component for TextArea {
                 placeholder=self.props.placeholder.clone()
                 disabled=self.props.disabled
                 readonly=self.props.readonly
+                autocomplete=self.props.autocomplete.clone()
+                maxlength=self.props.maxlength.map(|len| len.to_string())
+                minlength=self.props.minlength.map(|len| len.to_string())
+                inputmode=self.props.inputmode.clone()
                 />
-        }
+        };
+        with_control_attrs(textarea, control_attrs!(self.props))
     }
 }
@@ -0,0 +1,480 @@
+use std::fmt;
+use std::rc::Rc;
+
+use super::pattern;
+
+/// A validation rule for the value of a form control.
+///
+/// Rules apply to the plain-text value of a control. All rules other than `Required` accept empty
+/// values, so that optional fields are only validated once they are filled in.
+#[derive(Clone)]
+pub enum Rule {
+    /// The value must not be empty or whitespace.
+    Required,
+    /// The value must have at least the given number of characters.
+    MinLength(usize),
+    /// The value must have at most the given number of characters.
+    MaxLength(usize),
+    /// The whole value must match the given regular expression, as for the HTML `pattern`
+    /// attribute. Common regular expression syntax is supported, while backreferences, lookaround
+    /// & word boundaries are not; patterns which are not valid or not supported match any value.
+    Pattern(String),
+    /// The value must be an email address.
+    Email,
+    /// The value must be an absolute URL.
+    Url,
+    /// The value must be a number within the given inclusive bounds.
+    Range { min: Option<f64>, max: Option<f64> },
+    /// The value must be equal to the value of another field, described by `field`.
+    EqualTo { value: String, field: String },
+    /// The value must be accepted by the given function.
+    Custom(CustomFn),
+}
+
+/// The function of a custom validation rule, returning the error message for invalid values.
+pub type CustomFn = Rc<dyn Fn(&str) -> Result<(), String>>;
+
+impl Rule {
+    /// Check the given value against this rule.
+    pub fn check(&self, value: &str) -> Result<(), RuleError> {
+        if value.is_empty() && !matches!(self, Rule::Required | Rule::Custom(_)) {
+            return Ok(());
+        }
+        match self {
+            Rule::Required if value.trim().is_empty() => Err(RuleError::Required),
+            Rule::MinLength(min) if value.chars().count() < *min => Err(RuleError::TooShort { min: *min }),
+            Rule::MaxLength(max) if value.chars().count() > *max => Err(RuleError::TooLong { max: *max }),
+            Rule::Pattern(pattern) if !pattern::matches(pattern, value) => Err(RuleError::Pattern),
+            Rule::Email if !is_email(value) => Err(RuleError::Email),
+            Rule::Url if !is_url(value) => Err(RuleError::Url),
+            Rule::Range { min, max } => match value.trim().parse::<f64>() {
+                Err(_) => Err(RuleError::NotANumber),
+                Ok(number) if min.map(|min| number < min).unwrap_or(false) => Err(RuleError::BelowMin { min: min.unwrap_or_default() }),
+                Ok(number) if max.map(|max| number > max).unwrap_or(false) => Err(RuleError::AboveMax { max: max.unwrap_or_default() }),
+                Ok(_) => Ok(()),
+            },
+            Rule::EqualTo { value: other, field } if value != other => Err(RuleError::NotEqual { field: field.clone() }),
+            Rule::Custom(check) => check(value).map_err(RuleError::Custom),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Rules are equal when they are of the same kind & parameters, with custom rules sharing the
+/// same function.
+impl PartialEq for Rule {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Rule::Required, Rule::Required) | (Rule::Email, Rule::Email) | (Rule::Url, Rule::Url) => true,
+            (Rule::MinLength(a), Rule::MinLength(b)) | (Rule::MaxLength(a), Rule::MaxLength(b)) => a == b,
+            (Rule::Pattern(a), Rule::Pattern(b)) => a == b,
+            (Rule::Range { min: a_min, max: a_max }, Rule::Range { min: b_min, max: b_max }) => a_min == b_min && a_max == b_max,
+            (Rule::EqualTo { value: a, field: a_field }, Rule::EqualTo { value: b, field: b_field }) => a == b && a_field == b_field,
+            (Rule::Custom(a), Rule::Custom(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Debug for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rule::Required => f.write_str("Required"),
+            Rule::MinLength(min) => f.debug_tuple("MinLength").field(min).finish(),
+            Rule::MaxLength(max) => f.debug_tuple("MaxLength").field(max).finish(),
+            Rule::Pattern(pattern) => f.debug_tuple("Pattern").field(pattern).finish(),
+            Rule::Email => f.write_str("Email"),
+            Rule::Url => f.write_str("Url"),
+            Rule::Range { min, max } => f.debug_struct("Range").field("min", min).field("max", max).finish(),
+            Rule::EqualTo { value, field } => f
+                .debug_struct("EqualTo")
+                .field("value", value)
+                .field("field", field)
+                .finish(),
+            Rule::Custom(_) => f.debug_tuple("Custom").finish(),
+        }
+    }
+}
+
+/// The reason a value was rejected by a validation rule.
+///
+/// The `Display` implementation gives an English error message; use `Rules::messages` for
+/// localised messages.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RuleError {
+    /// The value is missing.
+    Required,
+    /// The value has fewer than `min` characters.
+    TooShort { min: usize },
+    /// The value has more than `max` characters.
+    TooLong { max: usize },
+    /// The value does not match the pattern.
+    Pattern,
+    /// The value is not an email address.
+    Email,
+    /// The value is not an absolute URL.
+    Url,
+    /// The value is not a number.
+    NotANumber,
+    /// The value is less than `min`.
+    BelowMin { min: f64 },
+    /// The value is greater than `max`.
+    AboveMax { max: f64 },
+    /// The value differs from the value of the given field.
+    NotEqual { field: String },
+    /// The error message of a custom rule.
+    Custom(String),
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleError::Required => write!(f, "This field is required."),
+            RuleError::TooShort { min } => write!(f, "Enter at least {} characters.", min),
+            RuleError::TooLong { max } => write!(f, "Enter at most {} characters.", max),
+            RuleError::Pattern => write!(f, "Enter a value in the requested format."),
+            RuleError::Email => write!(f, "Enter a valid email address."),
+            RuleError::Url => write!(f, "Enter a valid URL."),
+            RuleError::NotANumber => write!(f, "Enter a number."),
+            RuleError::BelowMin { min } => write!(f, "Enter a number no less than {}.", min),
+            RuleError::AboveMax { max } => write!(f, "Enter a number no greater than {}.", max),
+            RuleError::NotEqual { field } => write!(f, "This field must match {}.", field),
+            RuleError::Custom(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for RuleError {}
+
+type MessageFn = Rc<dyn Fn(&RuleError) -> String>;
+
+/// A set of validation rules, applied in order, for the `rules` prop of a form control or the
+/// `FormField::rules` validator.
+///
+/// ```
+/// use ybc::{RuleError, Rules};
+///
+/// let rules = Rules::new().required().min_length(8).custom(|value| {
+///     if value.chars().any(|c| c.is_ascii_digit()) { Ok(()) } else { Err("Include a digit.".into()) }
+/// });
+/// assert_eq!(rules.validate(""), Err(RuleError::Required));
+/// assert_eq!(rules.check("secret"), Err("Enter at least 8 characters.".into()));
+/// assert_eq!(rules.check("password"), Err("Include a digit.".into()));
+/// assert_eq!(rules.check("passw0rd"), Ok(()));
+///
+/// let rules = rules.messages(|err| match err {
+///     RuleError::Required => "Ce champ est obligatoire.".into(),
+///     err => err.to_string(),
+/// });
+/// assert_eq!(rules.check(""), Err("Ce champ est obligatoire.".into()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Rules {
+    rules: Vec<Rule>,
+    messages: Option<MessageFn>,
+}
+
+impl Rules {
+    /// Create a new, empty set of rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the given rule.
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Require a value which is not empty or whitespace.
+    pub fn required(self) -> Self {
+        self.rule(Rule::Required)
+    }
+
+    /// Require at least the given number of characters.
+    pub fn min_length(self, min: usize) -> Self {
+        self.rule(Rule::MinLength(min))
+    }
+
+    /// Require at most the given number of characters.
+    pub fn max_length(self, max: usize) -> Self {
+        self.rule(Rule::MaxLength(max))
+    }
+
+    /// Require the whole value to match the given regular expression.
+    pub fn pattern(self, pattern: impl Into<String>) -> Self {
+        self.rule(Rule::Pattern(pattern.into()))
+    }
+
+    /// Require an email address.
+    pub fn email(self) -> Self {
+        self.rule(Rule::Email)
+    }
+
+    /// Require an absolute URL.
+    pub fn url(self) -> Self {
+        self.rule(Rule::Url)
+    }
+
+    /// Require a number within the given inclusive bounds.
+    pub fn range(self, min: Option<f64>, max: Option<f64>) -> Self {
+        self.rule(Rule::Range { min, max })
+    }
+
+    /// Require the value to be equal to the given value of another field, described by `field`.
+    pub fn equal_to(self, value: impl Into<String>, field: impl Into<String>) -> Self {
+        self.rule(Rule::EqualTo { value: value.into(), field: field.into() })
+    }
+
+    /// Require the value to be accepted by the given function, returning the error message for
+    /// invalid values.
+    pub fn custom(self, check: impl Fn(&str) -> Result<(), String> + 'static) -> Self {
+        self.rule(Rule::Custom(Rc::new(check)))
+    }
+
+    /// Produce the error messages of these rules with the given function, such as for
+    /// localisation.
+    pub fn messages(mut self, messages: impl Fn(&RuleError) -> String + 'static) -> Self {
+        self.messages = Some(Rc::new(messages));
+        self
+    }
+
+    /// Check the given value against these rules, returning the error of the first rule which
+    /// rejects it.
+    pub fn validate(&self, value: &str) -> Result<(), RuleError> {
+        self.rules.iter().try_for_each(|rule| rule.check(value))
+    }
+
+    /// Check the given value against these rules, returning the error message of the first rule
+    /// which rejects it.
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        self.validate(value).map_err(|err| match &self.messages {
+            Some(messages) => messages(&err),
+            None => err.to_string(),
+        })
+    }
+
+    /// The Bulma class reflecting the validity of the given value.
+    pub(crate) fn class(&self, value: &str) -> &'static str {
+        match self.validate(value) {
+            Ok(()) => "is-success",
+            Err(_) => "is-danger",
+        }
+    }
+}
+
+/// Rule sets are equal when they have equal rules & share the same message function.
+impl PartialEq for Rules {
+    fn eq(&self, other: &Self) -> bool {
+        self.rules == other.rules
+            && match (&self.messages, &other.messages) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+impl fmt::Debug for Rules {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rules").field("rules", &self.rules).finish()
+    }
+}
+
+/// A value of a form field which can be checked against validation rules, as its plain text.
+pub trait RuleValue {
+    /// The plain-text value to be checked, where empty text denotes a missing value.
+    fn rule_value(&self) -> String;
+}
+
+impl RuleValue for String {
+    fn rule_value(&self) -> String {
+        self.clone()
+    }
+}
+
+/// Unchecked values are missing, so that `Required` requires a checked value.
+impl RuleValue for bool {
+    fn rule_value(&self) -> String {
+        if *self {
+            "true".into()
+        } else {
+            String::new()
+        }
+    }
+}
+
+impl<T: RuleValue> RuleValue for Option<T> {
+    fn rule_value(&self) -> String {
+        self.as_ref().map(RuleValue::rule_value).unwrap_or_default()
+    }
+}
+
+/// Lists are missing while empty; their items are joined with commas.
+impl<T: RuleValue> RuleValue for Vec<T> {
+    fn rule_value(&self) -> String {
+        self.iter().map(RuleValue::rule_value).collect::<Vec<_>>().join(",")
+    }
+}
+
+macro_rules! impl_rule_value {
+    ($($ty:ty),*) => {
+        $(impl RuleValue for $ty {
+            fn rule_value(&self) -> String {
+                self.to_string()
+            }
+        })*
+    };
+}
+
+impl_rule_value!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+fn is_email(value: &str) -> bool {
+    let (local, domain) = match value.rsplit_once('@') {
+        Some(parts) => parts,
+        None => return false,
+    };
+    !local.is_empty() && !local.contains(|c: char| c.is_whitespace() || c == '@') && domain.contains('.') && is_hostname(domain)
+}
+
+fn is_url(value: &str) -> bool {
+    let (scheme, rest) = match value.split_once("://") {
+        Some(parts) => parts,
+        None => return false,
+    };
+    let mut chars = scheme.chars();
+    let valid_scheme =
+        chars.next().map(|c| c.is_ascii_alphabetic()).unwrap_or(false) && chars.all(|c| c.is_ascii_alphanumeric() || ['+', '-', '.'].contains(&c));
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = authority.rsplit('@').next().unwrap_or_default();
+    let host = match host.rsplit_once(':') {
+        Some((host, port)) if port.chars().all(|c| c.is_ascii_digit()) => host,
+        _ => host,
+    };
+    valid_scheme && !value.contains(char::is_whitespace) && (is_hostname(host) || (host.starts_with('[') && host.ends_with(']')))
+}
+
+fn is_hostname(host: &str) -> bool {
+    !host.is_empty()
+        && host.split('.').all(|label| {
+            !label.is_empty() && !label.starts_with('-') && !label.ends_with('-') && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn required_rejects_blank_values() {
+        let rules = Rules::new().required();
+        assert_eq!(rules.validate(""), Err(RuleError::Required));
+        assert_eq!(rules.validate("  "), Err(RuleError::Required));
+        assert_eq!(rules.validate("a"), Ok(()));
+    }
+
+    #[test]
+    fn optional_rules_accept_empty_values() {
+        let rules = Rules::new().min_length(3).email().url().range(Some(1.0), None);
+        assert_eq!(rules.validate(""), Ok(()));
+    }
+
+    #[test]
+    fn lengths_count_characters() {
+        let rules = Rules::new().min_length(2).max_length(3);
+        assert_eq!(rules.validate("é"), Err(RuleError::TooShort { min: 2 }));
+        assert_eq!(rules.validate("éé"), Ok(()));
+        assert_eq!(rules.validate("éééé"), Err(RuleError::TooLong { max: 3 }));
+    }
+
+    #[test]
+    fn patterns_match_whole_values() {
+        let rules = Rules::new().pattern("[A-Z]{2}\\d{4}|N/A");
+        assert_eq!(rules.validate("AB1234"), Ok(()));
+        assert_eq!(rules.validate("N/A"), Ok(()));
+        assert_eq!(rules.validate("AB12345"), Err(RuleError::Pattern));
+        assert_eq!(rules.validate("ab1234"), Err(RuleError::Pattern));
+        assert_eq!(rules.validate(""), Ok(()));
+    }
+
+    #[test]
+    fn emails() {
+        for valid in ["ada@example.com", "a.b+c@mail.example.co.uk"] {
+            assert_eq!(Rule::Email.check(valid), Ok(()), "{}", valid);
+        }
+        for invalid in [
+            "ada",
+            "ada@",
+            "@example.com",
+            "ada@example",
+            "a da@example.com",
+            "ada@-example.com",
+            "ada@example..com",
+        ] {
+            assert_eq!(Rule::Email.check(invalid), Err(RuleError::Email), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn urls() {
+        for valid in [
+            "https://example.com",
+            "http://localhost:8080/path?q=1#top",
+            "ftp://user@files.example.com",
+            "http://[::1]/",
+        ] {
+            assert_eq!(Rule::Url.check(valid), Ok(()), "{}", valid);
+        }
+        for invalid in [
+            "example.com",
+            "https://",
+            "1http://example.com",
+            "https://exa mple.com",
+            "https://example.com:port",
+        ] {
+            assert_eq!(Rule::Url.check(invalid), Err(RuleError::Url), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn numeric_ranges() {
+        let rule = Rule::Range { min: Some(1.0), max: Some(10.0) };
+        assert_eq!(rule.check("abc"), Err(RuleError::NotANumber));
+        assert_eq!(rule.check("0.5"), Err(RuleError::BelowMin { min: 1.0 }));
+        assert_eq!(rule.check("11"), Err(RuleError::AboveMax { max: 10.0 }));
+        assert_eq!(rule.check(" 10 "), Ok(()));
+    }
+
+    #[test]
+    fn equal_to_other_field() {
+        let rules = Rules::new().equal_to("secret", "the password");
+        assert_eq!(rules.check("secrets"), Err("This field must match the password.".into()));
+        assert_eq!(rules.check("secret"), Ok(()));
+    }
+
+    #[test]
+    fn first_failing_rule_wins() {
+        let rules = Rules::new().required().min_length(3).custom(|_| Err("custom".into()));
+        assert_eq!(rules.validate(""), Err(RuleError::Required));
+        assert_eq!(rules.validate("ab"), Err(RuleError::TooShort { min: 3 }));
+        assert_eq!(rules.validate("abc"), Err(RuleError::Custom("custom".into())));
+    }
+
+    #[test]
+    fn messages_are_localised() {
+        let rules = Rules::new().min_length(3).messages(|err| match err {
+            RuleError::TooShort { min } => format!("Au moins {} caractères.", min),
+            err => err.to_string(),
+        });
+        assert_eq!(rules.check("ab"), Err("Au moins 3 caractères.".into()));
+    }
+
+    #[test]
+    fn rule_values() {
+        assert_eq!(true.rule_value(), "true");
+        assert_eq!(false.rule_value(), "");
+        assert_eq!(Some(5u32).rule_value(), "5");
+        assert_eq!(None::<u32>.rule_value(), "");
+        assert_eq!(Rules::new().required().validate(&false.rule_value()), Err(RuleError::Required));
+    }
+}
@@ -48,7 +48,10 @@ pub use components::pagination::{
     Pagination, PaginationEllipsis, PaginationItem, PaginationItemProps, PaginationItemRouter, PaginationItemType, PaginationProps,
 };
 pub use components::panel::{Panel, PanelBlock, PanelBlockProps, PanelProps, PanelTabs, PanelTabsProps};
-pub use components::tabs::{Tabs, TabsProps};
+pub use components::tabs::{
+    TabActivation, TabItem, TabPanelRender, TabView, TabViewMsg, TabViewProps, Tabs, TabsOverflow, TabsProps, TabsRouter, TabsRouterProps,
+};
+pub use components::virtual_list::{ItemRenderer, RowHeight, VirtualList, VirtualListMsg, VirtualListProps};
 
 // elements
 pub use elements::block::{Block, BlockProps};
@@ -57,6 +60,10 @@ pub use elements::button::{
     ButtonInputSubmitProps, ButtonProps, ButtonRouter, ButtonRouterProps, Buttons, ButtonsProps,
 };
 pub use elements::content::{Content, ContentProps};
+pub use elements::data_table::{
+    download_text, export_rows, write_delimited, CellEdit, CellEditor, ColumnPin, DataTable, DataTableMsg, DataTableProps, ExportFormat, FilterKind,
+    FilterValue, RowKey, RowTree, SortDirection, SortKey, TableColumn, TableLayout, TableQuery,
+};
 pub use elements::delete::{Delete, DeleteProps};
 pub use elements::icon::{Icon, IconProps};
 pub use elements::image::{Image, ImageProps, ImageSize};
@@ -68,14 +75,25 @@ pub use elements::tag::{Tag, TagProps, Tags, TagsProps};
 pub use elements::title::{HeaderSize, Subtitle, SubtitleProps, Title, TitleProps};
 
 // form
+pub use form::autocomplete::{Autocomplete, AutocompleteMsg, AutocompleteProps, Suggestions, SuggestionsFuture};
 pub use form::checkbox::{Checkbox, CheckboxProps};
 pub use form::control::{Control, ControlProps};
 pub use form::field::{AddonsAlign, Field, FieldProps, GroupedAlign, LabelSize};
-pub use form::file::{File, FileProps};
-pub use form::input::{Input, InputProps, InputType};
+pub use form::file::{File, FileMsg, FileProps};
+pub use form::file_drop_zone::{FileDropZone, FileDropZoneMsg, FileDropZoneProps, FileRejection};
+pub use form::file_reader::{FileContent, FileLoadError, LoadedFile, ReadAs};
+pub use form::form_state::{
+    AnyField, AutoForm, AutoFormProps, FieldOptions, Form, FormContext, FormControl, FormField, FormLayout, FormMsg, FormOptions, FormProps,
+    FormRenderer, ValidationFuture, YbcForm,
+};
+pub use form::input::{Input, InputProps, InputType, ParseError, TypedInput, TypedInputProps};
 pub use form::radio::{Radio, RadioProps};
-pub use form::select::{MultiSelect, MultiSelectProps, Select, SelectProps};
+pub use form::select::{MultiSelect, MultiSelectProps, Select, SelectProps, TypedMultiSelect, TypedMultiSelectProps, TypedSelect, TypedSelectProps};
+pub use form::tags_input::{TagError, TagsInput, TagsInputMsg, TagsInputProps};
 pub use form::textarea::{TextArea, TextAreaProps};
+pub use form::validation::{CustomFn, Rule, RuleError, RuleValue, Rules};
+#[cfg(feature = "derive")]
+pub use ybc_derive::{FormOptions, YbcForm};
 
 // layout
 pub use layout::container::{Container, ContainerProps};
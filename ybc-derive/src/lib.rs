@@ -0,0 +1,359 @@
+//! Derive macros for the [ybc](https://docs.rs/ybc) Bulma component library.
+//!
+//! These macros are re-exported by ybc when its `derive` feature is enabled, and should be used
+//! via those re-exports.
+//!
+//! `#[derive(YbcForm)]` implements `ybc::YbcForm` for a struct with named fields, so that it can
+//! be rendered as a form via `ybc::AutoForm`. Each field is rendered with the control for its
+//! type, as given by `ybc::FormControl`: `String` fields as an `Input`, `bool` fields as a
+//! `Checkbox`, numbers as a `TypedInput`, `Vec<String>` fields as a `MultiSelect`, and enums
+//! deriving `FormOptions` as a `Select` or `Radio` buttons.
+//!
+//! Fields are customised via `#[ybc(...)]` attributes:
+//!
+//! - `label = "..."`: the label of the field, defaulting to the field's name in sentence case.
+//! - `help = "..."`: the help message of the field, displayed while the field has no error.
+//! - `placeholder = "..."`: the placeholder of text & numeric controls.
+//! - `input_type = "Email"`: the `ybc::InputType` variant of text controls.
+//! - `textarea`: render a text field as a `TextArea`.
+//! - `radio`: render the options of an enum field as `Radio` buttons.
+//! - `choices = "path::to::fn"`: a function returning the `(value, label)` options of a text
+//!   field, rendered as a `Select`, or of a `Vec<String>` field.
+//! - `required`, `email`, `url`, `min_length = 3`, `max_length = 20`, `pattern = "[a-z]+"`,
+//!   `min = 0`, `max = 100`: the `ybc::Rules` of the field.
+//! - `rules = "path::to::fn"`: a function returning the `ybc::Rules` of the field given the model,
+//!   such as for requiring the field to be equal to another field.
+//! - `validate = "path::to::fn"`: a validator of the field, given the field's value & the model.
+//! - `skip`: leave the field out of the form.
+//!
+//! The struct itself takes the `#[ybc(horizontal)]` attribute for a horizontal layout.
+//!
+//! `#[derive(FormOptions)]` implements `ybc::FormOptions` for an enum of unit variants, with each
+//! variant labelled by its name in sentence case or by its `#[ybc(label = "...")]` attribute.
+//!
+//! ```
+//! use ybc::{FormOptions, YbcForm};
+//!
+//! #[derive(Clone, PartialEq, FormOptions)]
+//! enum Theme {
+//!     Light,
+//!     Dark,
+//!     #[ybc(label = "Follow the system")]
+//!     System,
+//! }
+//!
+//! #[derive(Clone, PartialEq, YbcForm)]
+//! #[ybc(horizontal)]
+//! struct Settings {
+//!     #[ybc(required, min_length = 3, placeholder = "Your name")]
+//!     display_name: String,
+//!     #[ybc(required, email, input_type = "Email", help = "We'll never share your email.")]
+//!     email: String,
+//!     #[ybc(min = 0, max = 150)]
+//!     age: Option<u32>,
+//!     #[ybc(radio)]
+//!     theme: Theme,
+//!     #[ybc(label = "Send me the newsletter")]
+//!     newsletter: bool,
+//!     #[ybc(skip)]
+//!     id: u64,
+//! }
+//!
+//! assert_eq!(Settings::layout(), ybc::FormLayout::Horizontal);
+//! assert_eq!(Settings::form_fields().len(), 5);
+//! assert_eq!(Theme::System.value(), "System");
+//! assert_eq!(Theme::options()[2].1, "Follow the system");
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, Ident, Lit, LitStr, Meta, NestedMeta, Path, Result};
+
+/// Derive `ybc::YbcForm` for a struct with named fields.
+#[proc_macro_derive(YbcForm, attributes(ybc))]
+pub fn derive_ybc_form(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_ybc_form(input).unwrap_or_else(|err| err.to_compile_error()).into()
+}
+
+/// Derive `ybc::FormOptions` for an enum of unit variants.
+#[proc_macro_derive(FormOptions, attributes(ybc))]
+pub fn derive_form_options(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_form_options(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+/// The attributes of a field of a `YbcForm` struct.
+#[derive(Default)]
+struct FieldAttrs {
+    label: Option<LitStr>,
+    help: Option<LitStr>,
+    placeholder: Option<LitStr>,
+    input_type: Option<Ident>,
+    textarea: bool,
+    radio: bool,
+    choices: Option<Path>,
+    skip: bool,
+    /// The calls of the `ybc::Rules` builder methods for the field's rules.
+    rules: Vec<TokenStream2>,
+    min: Option<f64>,
+    max: Option<f64>,
+    rules_with: Option<Path>,
+    validators: Vec<Path>,
+}
+
+impl FieldAttrs {
+    fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut parsed = Self::default();
+        for meta in ybc_args(attrs)? {
+            match &meta {
+                Meta::Path(path) => match ident_of(path).as_str() {
+                    "textarea" => parsed.textarea = true,
+                    "radio" => parsed.radio = true,
+                    "skip" => parsed.skip = true,
+                    "required" => parsed.rules.push(quote!(.required())),
+                    "email" => parsed.rules.push(quote!(.email())),
+                    "url" => parsed.rules.push(quote!(.url())),
+                    _ => return Err(Error::new_spanned(meta, "unknown ybc field attribute")),
+                },
+                Meta::NameValue(pair) => match (ident_of(&pair.path).as_str(), &pair.lit) {
+                    ("label", Lit::Str(lit)) => parsed.label = Some(lit.clone()),
+                    ("help", Lit::Str(lit)) => parsed.help = Some(lit.clone()),
+                    ("placeholder", Lit::Str(lit)) => parsed.placeholder = Some(lit.clone()),
+                    ("input_type", Lit::Str(lit)) => parsed.input_type = Some(lit.parse()?),
+                    ("choices", Lit::Str(lit)) => parsed.choices = Some(lit.parse()?),
+                    ("rules", Lit::Str(lit)) => parsed.rules_with = Some(lit.parse()?),
+                    ("validate", Lit::Str(lit)) => parsed.validators.push(lit.parse()?),
+                    ("pattern", Lit::Str(lit)) => parsed.rules.push(quote!(.pattern(#lit))),
+                    ("min_length", Lit::Int(lit)) => {
+                        let min = lit.base10_parse::<usize>()?;
+                        parsed.rules.push(quote!(.min_length(#min)));
+                    }
+                    ("max_length", Lit::Int(lit)) => {
+                        let max = lit.base10_parse::<usize>()?;
+                        parsed.rules.push(quote!(.max_length(#max)));
+                    }
+                    ("min", lit) => parsed.min = Some(number_of(lit)?),
+                    ("max", lit) => parsed.max = Some(number_of(lit)?),
+                    _ => return Err(Error::new_spanned(meta, "unknown or invalid ybc field attribute")),
+                },
+                Meta::List(_) => return Err(Error::new_spanned(meta, "unknown ybc field attribute")),
+            }
+        }
+        if parsed.min.is_some() || parsed.max.is_some() {
+            let min = option_tokens(parsed.min.map(|min| quote!(#min)));
+            let max = option_tokens(parsed.max.map(|max| quote!(#max)));
+            parsed.rules.push(quote!(.range(#min, #max)));
+        }
+        Ok(parsed)
+    }
+}
+
+fn expand_ybc_form(input: DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(name, "YbcForm can only be derived for structs with named fields")),
+        },
+        _ => return Err(Error::new_spanned(name, "YbcForm can only be derived for structs")),
+    };
+
+    let mut horizontal = false;
+    for meta in ybc_args(&input.attrs)? {
+        match &meta {
+            Meta::Path(path) if path.is_ident("horizontal") => horizontal = true,
+            _ => return Err(Error::new_spanned(meta, "unknown ybc struct attribute")),
+        }
+    }
+
+    let mut constructors = vec![];
+    let mut options = vec![];
+    for field in fields {
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+        let ident = field.ident.as_ref().expect("named fields have identifiers");
+        let field_name = ident.to_string().trim_start_matches("r#").to_string();
+
+        let rules = &attrs.rules;
+        let rules = (!rules.is_empty()).then(|| quote!(.rules(::ybc::Rules::new() #(#rules)*)));
+        let rules_with = attrs.rules_with.as_ref().map(|path| quote!(.rules_with(#path)));
+        let validators = &attrs.validators;
+        constructors.push(quote! {
+            ::ybc::FormField::new(
+                #field_name,
+                |model: &Self| ::std::clone::Clone::clone(&model.#ident),
+                |model: &mut Self, value| model.#ident = value,
+            )
+            #rules
+            #rules_with
+            #(.validate(#validators))*
+        });
+
+        let label = attrs
+            .label
+            .map(|lit| lit.value())
+            .unwrap_or_else(|| sentence_case(&field_name));
+        let help = option_tokens(attrs.help.map(|lit| quote!(::std::string::String::from(#lit))));
+        let placeholder = option_tokens(attrs.placeholder.map(|lit| quote!(::std::string::String::from(#lit))));
+        let input_type = option_tokens(attrs.input_type.map(|ident| quote!(::ybc::InputType::#ident)));
+        let choices = option_tokens(attrs.choices.map(|path| quote!(#path())));
+        let (textarea, radio) = (attrs.textarea, attrs.radio);
+        options.push(quote! {
+            ::ybc::FieldOptions {
+                label: ::std::string::String::from(#label),
+                help: #help,
+                placeholder: #placeholder,
+                input_type: #input_type,
+                textarea: #textarea,
+                radio: #radio,
+                choices: #choices,
+                layout,
+                id: ::std::option::Option::None,
+            }
+        });
+    }
+
+    let layout = horizontal.then(|| {
+        quote! {
+            fn layout() -> ::ybc::FormLayout {
+                ::ybc::FormLayout::Horizontal
+            }
+        }
+    });
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::ybc::YbcForm for #name #ty_generics #where_clause {
+            #layout
+
+            fn form_fields() -> ::std::vec::Vec<::ybc::AnyField<Self>> {
+                ::std::vec![#(::std::convert::Into::into(#constructors)),*]
+            }
+
+            fn view_fields(ctx: &::ybc::FormContext<'_, Self>, layout: ::ybc::FormLayout) -> ::yew::Html {
+                let fields: ::std::vec::Vec<::yew::Html> = ::std::vec![#(ctx.view_control(&#constructors, &#options)),*];
+                fields.into_iter().collect()
+            }
+        }
+    })
+}
+
+fn expand_form_options(input: DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => return Err(Error::new_spanned(name, "FormOptions can only be derived for enums")),
+    };
+
+    let mut idents = vec![];
+    let mut values = vec![];
+    let mut labels = vec![];
+    for variant in variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(variant, "FormOptions can only be derived for enums of unit variants"));
+        }
+        let mut label = None;
+        for meta in ybc_args(&variant.attrs)? {
+            match &meta {
+                Meta::NameValue(pair) if pair.path.is_ident("label") => match &pair.lit {
+                    Lit::Str(lit) => label = Some(lit.value()),
+                    lit => return Err(Error::new_spanned(lit, "expected a string label")),
+                },
+                _ => return Err(Error::new_spanned(meta, "unknown ybc variant attribute")),
+            }
+        }
+        let value = variant.ident.to_string();
+        labels.push(label.unwrap_or_else(|| sentence_case(&value)));
+        values.push(value);
+        idents.push(&variant.ident);
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::ybc::FormOptions for #name #ty_generics #where_clause {
+            fn options() -> ::std::vec::Vec<(Self, ::std::string::String)> {
+                ::std::vec![#((#name::#idents, ::std::string::String::from(#labels))),*]
+            }
+
+            fn value(&self) -> ::std::string::String {
+                match self {
+                    #(#name::#idents => ::std::string::String::from(#values),)*
+                }
+            }
+        }
+
+        impl #impl_generics ::ybc::RuleValue for #name #ty_generics #where_clause {
+            fn rule_value(&self) -> ::std::string::String {
+                ::ybc::FormOptions::value(self)
+            }
+        }
+    })
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+/// Collect the arguments of all `#[ybc(...)]` attributes.
+fn ybc_args(attrs: &[Attribute]) -> Result<Vec<Meta>> {
+    let mut args = vec![];
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("ybc")) {
+        match attr.parse_meta()? {
+            Meta::List(list) => {
+                for nested in list.nested {
+                    match nested {
+                        NestedMeta::Meta(meta) => args.push(meta),
+                        NestedMeta::Lit(lit) => return Err(Error::new_spanned(lit, "expected a ybc attribute")),
+                    }
+                }
+            }
+            meta => return Err(Error::new_spanned(meta, "expected `#[ybc(...)]`")),
+        }
+    }
+    Ok(args)
+}
+
+fn ident_of(path: &Path) -> String {
+    path.get_ident().map(ToString::to_string).unwrap_or_default()
+}
+
+fn number_of(lit: &Lit) -> Result<f64> {
+    match lit {
+        Lit::Int(lit) => lit.base10_parse(),
+        Lit::Float(lit) => lit.base10_parse(),
+        lit => Err(Error::new_spanned(lit, "expected a number")),
+    }
+}
+
+fn option_tokens(tokens: Option<TokenStream2>) -> TokenStream2 {
+    match tokens {
+        Some(tokens) => quote!(::std::option::Option::Some(#tokens)),
+        None => quote!(::std::option::Option::None),
+    }
+}
+
+/// Convert a `snake_case` or `CamelCase` identifier to sentence case, e.g. `display_name` or
+/// `DisplayName` to `Display name`.
+fn sentence_case(ident: &str) -> String {
+    let mut words = String::new();
+    for (idx, c) in ident.chars().enumerate() {
+        if c == '_' {
+            words.push(' ');
+        } else if c.is_uppercase() && idx > 0 {
+            words.push(' ');
+            words.extend(c.to_lowercase());
+        } else {
+            words.push(c);
+        }
+    }
+    let mut chars = words.trim().chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}